@@ -81,8 +81,12 @@ impl Default for ExampleData {
 }
 
 impl MemoryEditorContext for ExampleData {
-    fn data(&self, range: Range<usize>) -> Vec<u8> {
-        self.data[range].into()
+    fn data(&self, range: Range<usize>) -> Vec<Option<u8>> {
+        range.map(|addr| self.data.get(addr).copied()).collect()
+    }
+
+    fn address_range(&self) -> Range<usize> {
+        0..self.data.len()
     }
 
     fn options(&self) -> MemoryEditorOptions {
@@ -0,0 +1,167 @@
+use crate::options::CopyFormat;
+
+const ARRAY_ITEMS_PER_LINE: usize = 16;
+
+/// Largest selection copied at once, larger ones are refused rather than read whole.
+pub const MAX_COPY_BYTES: usize = 1 << 20;
+
+/// Formats `data` for the clipboard. Arrays can't hold unreadable bytes, those
+/// selections are copied as hex instead.
+pub fn copy_text(data: &[Option<u8>], format: CopyFormat) -> String {
+    match format {
+        CopyFormat::Hex => data
+            .iter()
+            .map(|byte| match byte {
+                Some(byte) => format!("{:02X}", byte),
+                None => "??".to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" "),
+        CopyFormat::Ascii => data
+            .iter()
+            .map(|byte| match byte {
+                Some(byte) if (32..=126).contains(byte) => *byte as char,
+                Some(_) => '.',
+                None => '?',
+            })
+            .collect(),
+        CopyFormat::CArray => match array_items(data) {
+            Some(items) => format!("unsigned char data[{}] = {{{}}};", data.len(), items),
+            None => copy_text(data, CopyFormat::Hex),
+        },
+        CopyFormat::RustArray => match array_items(data) {
+            Some(items) => format!("let data: [u8; {}] = [{}];", data.len(), items),
+            None => copy_text(data, CopyFormat::Hex),
+        },
+    }
+}
+
+fn array_items(data: &[Option<u8>]) -> Option<String> {
+    let bytes: Vec<u8> = data.iter().copied().collect::<Option<_>>()?;
+
+    if bytes.len() <= ARRAY_ITEMS_PER_LINE {
+        return Some(
+            bytes
+                .iter()
+                .map(|byte| format!("0x{:02X}", byte))
+                .collect::<Vec<_>>()
+                .join(", "),
+        );
+    }
+
+    let lines: Vec<String> = bytes
+        .chunks(ARRAY_ITEMS_PER_LINE)
+        .map(|line| {
+            let items: Vec<String> = line.iter().map(|byte| format!("0x{:02X}", byte)).collect();
+            format!("    {},", items.join(", "))
+        })
+        .collect();
+
+    Some(format!("\n{}\n", lines.join("\n")))
+}
+
+/// Decodes text pasted in the hex column: hex digit pairs, optionally prefixed with `0x`
+/// and separated by whitespace or commas. `??` pairs, as copied from unreadable bytes,
+/// leave their address untouched. `None` if the text isn't hex.
+pub fn paste_hex(text: &str) -> Option<Vec<Option<u8>>> {
+    let mut bytes = Vec::new();
+
+    for token in text
+        .split(|ch: char| ch.is_whitespace() || ch == ',')
+        .filter(|token| !token.is_empty())
+    {
+        let token = token
+            .strip_prefix("0x")
+            .or_else(|| token.strip_prefix("0X"))
+            .unwrap_or(token);
+
+        if token.is_empty() || token.len() % 2 != 0 || !token.is_ascii() {
+            return None;
+        }
+
+        for pair in token.as_bytes().chunks(2) {
+            let pair = std::str::from_utf8(pair).ok()?;
+            bytes.push(match pair {
+                "??" => None,
+                _ => Some(u8::from_str_radix(pair, 16).ok()?),
+            });
+        }
+    }
+
+    if bytes.is_empty() {
+        None
+    } else {
+        Some(bytes)
+    }
+}
+
+/// Text pasted in the ASCII column is written byte for byte.
+pub fn paste_text(text: &str) -> Vec<Option<u8>> {
+    text.bytes().map(Some).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copy_formats() {
+        let data = [Some(0x41), Some(0x00), Some(0xFF)];
+
+        assert_eq!(copy_text(&data, CopyFormat::Hex), "41 00 FF");
+        assert_eq!(copy_text(&data, CopyFormat::Ascii), "A..");
+        assert_eq!(
+            copy_text(&data, CopyFormat::CArray),
+            "unsigned char data[3] = {0x41, 0x00, 0xFF};"
+        );
+        assert_eq!(
+            copy_text(&data, CopyFormat::RustArray),
+            "let data: [u8; 3] = [0x41, 0x00, 0xFF];"
+        );
+    }
+
+    #[test]
+    fn long_arrays_wrap() {
+        let data = [Some(0); ARRAY_ITEMS_PER_LINE + 1];
+        let text = copy_text(&data, CopyFormat::RustArray);
+
+        assert!(text.starts_with("let data: [u8; 17] = [\n    0x00,"));
+        assert_eq!(text.lines().count(), 4);
+    }
+
+    #[test]
+    fn unreadable_bytes() {
+        let data = [None, Some(0x01), Some(0x02)];
+
+        assert_eq!(copy_text(&data, CopyFormat::Hex), "?? 01 02");
+        assert_eq!(copy_text(&data, CopyFormat::Ascii), "?..");
+        assert_eq!(copy_text(&data, CopyFormat::CArray), "?? 01 02");
+        assert_eq!(copy_text(&data, CopyFormat::RustArray), "?? 01 02");
+    }
+
+    #[test]
+    fn paste_hex_pairs() {
+        assert_eq!(
+            paste_hex("0x41, 0X42 4344"),
+            Some(vec![Some(0x41), Some(0x42), Some(0x43), Some(0x44)])
+        );
+        assert_eq!(paste_hex("ab\ncd"), Some(vec![Some(0xAB), Some(0xCD)]));
+        assert_eq!(paste_hex("hi!"), None);
+        assert_eq!(paste_hex("0x"), None);
+        assert_eq!(paste_hex("ABC"), None);
+    }
+
+    #[test]
+    fn paste_skips_unreadable_bytes() {
+        assert_eq!(
+            paste_hex(&copy_text(&[None, Some(0x01), Some(0x02)], CopyFormat::Hex)),
+            Some(vec![None, Some(0x01), Some(0x02)])
+        );
+    }
+
+    #[test]
+    fn paste_ascii() {
+        assert_eq!(paste_text("cafe"), b"cafe".map(Some));
+        assert_eq!(paste_text("00"), [Some(b'0'), Some(b'0')]);
+    }
+}
@@ -1,8 +1,15 @@
-use crate::options::{MemoryEditorOptions, PreviewDataFormat};
-use std::{cell::RefCell, ops::Range};
+use crate::history::{ByteEdit, History};
+use crate::options::{
+    CellRadix, CellSize, CopyFormat, Endianness, FloatFormat, MemoryEditorOptions,
+    PreviewDataFormat,
+};
+use crate::search::Direction;
+use crate::selection::Selection;
+use std::{cell::RefCell, collections::BTreeMap, ops::Range};
 
 pub trait MemoryEditorContext {
-    fn data(&self, range: Range<usize>) -> Vec<u8>;
+    fn data(&self, range: Range<usize>) -> Vec<Option<u8>>;
+    fn address_range(&self) -> Range<usize>;
     fn options(&self) -> MemoryEditorOptions;
     fn write_options(&mut self, options: MemoryEditorOptions);
     fn can_write(&self, address: usize) -> bool;
@@ -14,9 +21,33 @@ pub trait MemoryEditorContext {
 pub enum Action {
     ShowASCIIUpdate(bool),
     PreviewFormatUpdate(PreviewDataFormat),
+    CopyFormatUpdate(CopyFormat),
+    EndiannessUpdate(Endianness),
+    FloatFormatUpdate(FloatFormat),
+    FloatPrecisionUpdate(usize),
+    CellRadixUpdate(CellRadix),
+    CellSizeUpdate(CellSize),
     RowLengthUpdate(usize),
     UpdateByte(usize, u8),
+    UpdateBytes(usize, Vec<Option<u8>>),
+    WriteBytes(Vec<(usize, u8)>),
+    SelectionUpdate(Option<Selection>),
+    StartAddressUpdate(usize),
+    Undo,
+    Redo,
+    Search(Direction),
+    FindAll,
+    SearchResultSelected(usize),
+    Replace,
+    ReplaceAll,
+    FirstScan,
+    NextScan,
+    ResetScan,
+    ScanResultSelected(usize),
     OptionsToggled,
+    SearchToggled,
+    ScanToggled,
+    InspectorToggled,
 }
 
 pub struct Content<C: MemoryEditorContext> {
@@ -25,13 +56,130 @@ pub struct Content<C: MemoryEditorContext> {
 
 pub struct Internal<C: MemoryEditorContext> {
     pub context: C,
+    pub history: History,
+    pub(crate) staged: bool,
+    pub(crate) pending: BTreeMap<usize, u8>,
+    pub(crate) stale: bool,
+    pub(crate) committed: Vec<(usize, u8)>,
+}
+
+impl<C: MemoryEditorContext> Internal<C> {
+    fn apply(&mut self, writes: &[(usize, u8)]) {
+        let mut edits = Vec::with_capacity(writes.len());
+
+        for &(address, value) in writes {
+            let old = address
+                .checked_add(1)
+                .and_then(|end| self.context.data(address..end).first().copied().flatten());
+            self.context.write(address, value);
+            if let Some(old) = old {
+                edits.push(ByteEdit {
+                    address,
+                    old,
+                    new: value,
+                });
+            }
+        }
+
+        self.history.record(edits);
+    }
 }
 
 impl<C: MemoryEditorContext> Content<C> {
     pub fn new(context: C) -> Self {
         Self {
-            internal: RefCell::new(Internal { context }),
+            internal: RefCell::new(Internal {
+                context,
+                history: History::default(),
+                staged: false,
+                pending: BTreeMap::new(),
+                stale: false,
+                committed: Vec::new(),
+            }),
         }
     }
-}
 
+    /// Reverts the latest recorded edit, returning the bytes it touched.
+    pub fn undo(&self) -> Option<Vec<ByteEdit>> {
+        let mut internal = self.internal.borrow_mut();
+        let edits = internal.history.undo()?;
+        for edit in edits.iter().rev() {
+            internal.context.write(edit.address, edit.old);
+        }
+        internal.stale = true;
+        Some(edits)
+    }
+
+    /// Applies the latest undone edit again, returning the bytes it touched.
+    pub fn redo(&self) -> Option<Vec<ByteEdit>> {
+        let mut internal = self.internal.borrow_mut();
+        let edits = internal.history.redo()?;
+        for edit in &edits {
+            internal.context.write(edit.address, edit.new);
+        }
+        internal.stale = true;
+        Some(edits)
+    }
+
+    /// When staged, edits made in the widget are kept in a pending overlay
+    /// until `commit()` or `discard()` is called.
+    pub fn set_staged(&self, staged: bool) {
+        self.internal.borrow_mut().staged = staged;
+    }
+
+    pub fn is_staged(&self) -> bool {
+        self.internal.borrow().staged
+    }
+
+    pub fn pending(&self) -> Vec<(usize, u8)> {
+        let internal = self.internal.borrow();
+        internal.pending.iter().map(|(&a, &v)| (a, v)).collect()
+    }
+
+    /// Writes all pending edits to the context as a single undo step, dropping the ones
+    /// that can no longer be written. The widget reports the written bytes through
+    /// `on_write` on its next update.
+    pub fn commit(&self) -> Vec<(usize, u8)> {
+        let mut internal = self.internal.borrow_mut();
+        let writes: Vec<(usize, u8)> = std::mem::take(&mut internal.pending)
+            .into_iter()
+            .filter(|&(address, _)| internal.context.can_write(address))
+            .collect();
+        internal.apply(&writes);
+        internal.committed.extend(writes.iter().copied());
+        internal.stale = true;
+        writes
+    }
+
+    pub fn discard(&self) {
+        let mut internal = self.internal.borrow_mut();
+        internal.pending.clear();
+        internal.stale = true;
+    }
+
+    pub(crate) fn is_pending(&self, address: usize) -> bool {
+        self.internal.borrow().pending.contains_key(&address)
+    }
+
+    pub(crate) fn data(&self, range: Range<usize>) -> Vec<Option<u8>> {
+        let internal = self.internal.borrow();
+        let mut data = internal.context.data(range.clone());
+        for (&address, &value) in internal.pending.range(range.clone()) {
+            if let Some(byte) = data.get_mut(address - range.start) {
+                *byte = Some(value);
+            }
+        }
+        data
+    }
+
+    /// Returns false if the writes went to the pending overlay instead of the context.
+    pub(crate) fn write(&self, writes: &[(usize, u8)]) -> bool {
+        let mut internal = self.internal.borrow_mut();
+        if internal.staged {
+            internal.pending.extend(writes.iter().copied());
+            return false;
+        }
+        internal.apply(writes);
+        true
+    }
+}
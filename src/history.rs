@@ -0,0 +1,47 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ByteEdit {
+    pub address: usize,
+    pub old: u8,
+    pub new: u8,
+}
+
+#[derive(Debug, Default)]
+pub struct History {
+    undo: Vec<Vec<ByteEdit>>,
+    redo: Vec<Vec<ByteEdit>>,
+}
+
+impl History {
+    pub fn record(&mut self, edits: Vec<ByteEdit>) {
+        if edits.is_empty() {
+            return;
+        }
+        self.undo.push(edits);
+        self.redo.clear();
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    pub(crate) fn undo(&mut self) -> Option<Vec<ByteEdit>> {
+        let edits = self.undo.pop()?;
+        self.redo.push(edits.clone());
+        Some(edits)
+    }
+
+    pub(crate) fn redo(&mut self) -> Option<Vec<ByteEdit>> {
+        let edits = self.redo.pop()?;
+        self.undo.push(edits.clone());
+        Some(edits)
+    }
+}
@@ -0,0 +1,125 @@
+use crate::options::{Endianness, MemoryEditorOptions, PreviewDataFormat};
+use crate::value::{self, Value};
+
+pub(crate) const INSPECTOR_BYTES: usize = 8;
+
+const WIDE_FORMATS: [(PreviewDataFormat, &str); 8] = [
+    (PreviewDataFormat::U16, "U16"),
+    (PreviewDataFormat::I16, "I16"),
+    (PreviewDataFormat::U32, "U32"),
+    (PreviewDataFormat::I32, "I32"),
+    (PreviewDataFormat::U64, "U64"),
+    (PreviewDataFormat::I64, "I64"),
+    (PreviewDataFormat::F32, "F32"),
+    (PreviewDataFormat::F64, "F64"),
+];
+
+pub(crate) type Entry = (String, String);
+
+/// Decodes the bytes at the cursor in every width and byte order, as three
+/// columns: little endian, big endian, and the byte order independent ones.
+pub(crate) fn entries(
+    data: &[Option<u8>],
+    pointer_size: usize,
+    options: &MemoryEditorOptions,
+) -> [Vec<Entry>; 3] {
+    let endianness = options.endianness;
+    let bytes: Vec<u8> = data.iter().map_while(|byte| *byte).collect();
+
+    let wide = |endianness: Endianness, suffix: &str| {
+        WIDE_FORMATS
+            .iter()
+            .map(|(format, name)| {
+                (
+                    format!("{} {}", name, suffix),
+                    decoded(&bytes, *format, endianness, options),
+                )
+            })
+            .collect()
+    };
+
+    let byte = bytes.first();
+    let pointer = value::decode(&bytes, pointer_format(pointer_size), endianness);
+
+    [
+        wide(Endianness::Little, "LE"),
+        wide(Endianness::Big, "BE"),
+        vec![
+            (
+                "U8".into(),
+                decoded(&bytes, PreviewDataFormat::U8, endianness, options),
+            ),
+            (
+                "I8".into(),
+                decoded(&bytes, PreviewDataFormat::I8, endianness, options),
+            ),
+            (
+                "Binary".into(),
+                byte.map_or("??".into(), |byte| format!("{:08b}", byte)),
+            ),
+            (
+                "Octal".into(),
+                byte.map_or("??".into(), |byte| format!("{:03o}", byte)),
+            ),
+            ("UTF-8".into(), utf8_char(&bytes)),
+            (
+                "Pointer".into(),
+                match pointer {
+                    Some(Value::Unsigned(addr)) => {
+                        format!("{:0width$X}", addr, width = pointer_size * 2)
+                    }
+                    _ => "??".into(),
+                },
+            ),
+        ],
+    ]
+}
+
+/// The smallest pointer width able to address the whole range.
+pub(crate) fn pointer_size(end: usize) -> usize {
+    match end {
+        0..=0x1_0000 => 2,
+        0x1_0001..=0xFFFF_FFFF => 4,
+        _ => 8,
+    }
+}
+
+fn pointer_format(size: usize) -> PreviewDataFormat {
+    match size {
+        2 => PreviewDataFormat::U16,
+        4 => PreviewDataFormat::U32,
+        _ => PreviewDataFormat::U64,
+    }
+}
+
+fn decoded(
+    bytes: &[u8],
+    format: PreviewDataFormat,
+    endianness: Endianness,
+    options: &MemoryEditorOptions,
+) -> String {
+    value::format_float(
+        bytes,
+        format,
+        endianness,
+        options.float_format,
+        options.float_precision,
+    )
+    .or_else(|| value::decode(bytes, format, endianness).map(|value| value.to_string()))
+    .unwrap_or_else(|| "??".into())
+}
+
+fn utf8_char(bytes: &[u8]) -> String {
+    let Some(c) = (1..=bytes.len().min(4))
+        .find_map(|len| std::str::from_utf8(&bytes[..len]).ok())
+        .and_then(|text| text.chars().next())
+    else {
+        return "-".into();
+    };
+
+    if c.is_control() {
+        format!("U+{:04X}", c as u32)
+    } else {
+        format!("{} U+{:04X}", c, c as u32)
+    }
+}
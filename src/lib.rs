@@ -1,8 +1,15 @@
+pub mod clipboard;
 pub mod context;
+pub mod history;
+pub mod inspector;
 pub mod memory_editor;
 pub mod options;
+pub mod scan;
+pub mod search;
+pub mod selection;
 pub mod state;
 pub mod style;
+pub mod value;
 
 #[cfg(all(feature = "iced", feature = "libcosmic"))]
 compile_error!("feature \"iced\" and feature \"libcosmic\" cannot be enabled at the same time");
@@ -1,7 +1,14 @@
+use crate::clipboard::{copy_text, paste_hex, paste_text, MAX_COPY_BYTES};
 use crate::context::{self, Action, Content, MemoryEditorContext};
-use crate::options::{MemoryEditorOptions, PreviewDataFormat};
-use crate::state::State;
+use crate::inspector::{self, INSPECTOR_BYTES};
+use crate::options::{CellRadix, MemoryEditorOptions, PreviewDataFormat};
+use crate::scan::{ScanFilter, Scanner};
+use crate::search::{Direction, Pattern, Progress, Search, SearchMode, Status, MAX_RESULTS};
+use crate::selection::Selection;
+use crate::state::{InputState, Section, SelectorBounds, State, OPTIONS_PANEL_LINES};
 use crate::style::Style;
+use crate::value::{self, Value};
+use std::any::Any;
 use std::f32;
 use std::ops::Range;
 
@@ -15,8 +22,8 @@ use iced_core::{
     renderer::{self, Quad},
     text::Paragraph as _,
     widget::text::{Alignment, LineHeight, Shaping, Wrapping},
-    widget::{self, tree::Tree, Widget},
-    {keyboard, Border, Element, Event, Length, Point, Rectangle, Size}, {mouse, Text},
+    widget::{self, operation::focusable, operation::Operation, tree::Tree, Id, Widget},
+    {keyboard, Border, Color, Element, Event, Length, Point, Rectangle, Size}, {mouse, Text},
 };
 #[cfg(feature = "iced")]
 use iced_renderer::graphics::text::Paragraph;
@@ -29,14 +36,46 @@ use cosmic::iced_core::{
     renderer::{self, Quad},
     text::Paragraph as _,
     widget::text::{LineHeight, Shaping, Wrapping},
-    widget::{self, tree::Tree, Widget},
-    {keyboard, Border, Event, Length, Point, Rectangle, Size}, {mouse, Text},
+    widget::{self, operation::focusable, operation::Operation, tree::Tree, Id, Widget},
+    {keyboard, Border, Color, Event, Length, Point, Rectangle, Size}, {mouse, Text},
 };
 #[cfg(feature = "libcosmic")]
 use cosmic::iced_widget::graphics::text::Paragraph;
 
+const VALUE_INPUT_LEN: usize = 48;
+
+struct Bindings<'a, Message> {
+    on_select: Option<Box<dyn Fn(Option<Selection>) -> Message + 'a>>,
+    on_write: Option<Box<dyn Fn(usize, u8) -> Message + 'a>>,
+    on_scroll: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    start_address: Option<usize>,
+    selection: Option<Option<Selection>>,
+}
+
+impl<Message> Default for Bindings<'_, Message> {
+    fn default() -> Self {
+        Self {
+            on_select: None,
+            on_write: None,
+            on_scroll: None,
+            start_address: None,
+            selection: None,
+        }
+    }
+}
+
+impl<Message> Bindings<'_, Message> {
+    fn state(&self) -> State {
+        State {
+            start_address: self.start_address.unwrap_or_default(),
+            selection: self.selection.flatten(),
+            ..State::default()
+        }
+    }
+}
+
 #[cfg(feature = "iced")]
-pub struct MemoryEditor<'a, Context, Theme>
+pub struct MemoryEditor<'a, Context, Message, Theme>
 where
     Theme: Catalog + iced_core::widget::text::Catalog,
     Context: context::MemoryEditorContext,
@@ -44,10 +83,12 @@ where
     content: &'a Content<Context>,
     class: <Theme as crate::style::Catalog>::Class<'a>,
     style: Option<Style>,
+    id: Option<Id>,
+    bindings: Bindings<'a, Message>,
 }
 
 #[cfg(feature = "iced")]
-impl<'a, Context, Theme> MemoryEditor<'a, Context, Theme>
+impl<'a, Context, Message, Theme> MemoryEditor<'a, Context, Message, Theme>
 where
     Theme: Catalog + iced_core::widget::text::Catalog + 'a,
     Context: context::MemoryEditorContext,
@@ -57,21 +98,25 @@ where
             class: <Theme as crate::style::Catalog>::default(),
             content,
             style: None,
+            id: None,
+            bindings: Bindings::default(),
         }
     }
 }
 
 #[cfg(feature = "libcosmic")]
-pub struct MemoryEditor<'a, Context>
+pub struct MemoryEditor<'a, Context, Message>
 where
     Context: context::MemoryEditorContext + 'a,
 {
     content: &'a Content<Context>,
     style: Option<Style>,
+    id: Option<Id>,
+    bindings: Bindings<'a, Message>,
 }
 
 #[cfg(feature = "libcosmic")]
-impl<'a, Context> MemoryEditor<'a, Context>
+impl<'a, Context, Message> MemoryEditor<'a, Context, Message>
 where
     Context: context::MemoryEditorContext + 'a,
 {
@@ -79,12 +124,14 @@ where
         MemoryEditor {
             content,
             style: None,
+            id: None,
+            bindings: Bindings::default(),
         }
     }
 }
 
 #[cfg(feature = "iced")]
-impl<'a, Context, Theme> MemoryEditor<'a, Context, Theme>
+impl<'a, Context, Message, Theme> MemoryEditor<'a, Context, Message, Theme>
 where
     Theme: Catalog + iced_core::widget::text::Catalog + 'a,
     Context: context::MemoryEditorContext + 'a,
@@ -93,10 +140,40 @@ where
         self.style = Some(style);
         self
     }
+
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn on_select(mut self, on_select: impl Fn(Option<Selection>) -> Message + 'a) -> Self {
+        self.bindings.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    pub fn on_write(mut self, on_write: impl Fn(usize, u8) -> Message + 'a) -> Self {
+        self.bindings.on_write = Some(Box::new(on_write));
+        self
+    }
+
+    pub fn on_scroll(mut self, on_scroll: impl Fn(usize) -> Message + 'a) -> Self {
+        self.bindings.on_scroll = Some(Box::new(on_scroll));
+        self
+    }
+
+    pub fn start_address(mut self, address: usize) -> Self {
+        self.bindings.start_address = Some(address);
+        self
+    }
+
+    pub fn selection(mut self, selection: Option<Selection>) -> Self {
+        self.bindings.selection = Some(selection);
+        self
+    }
 }
 
 #[cfg(feature = "libcosmic")]
-impl<'a, Context> MemoryEditor<'a, Context>
+impl<'a, Context, Message> MemoryEditor<'a, Context, Message>
 where
     Context: context::MemoryEditorContext + 'a,
 {
@@ -104,11 +181,41 @@ where
         self.style = Some(style);
         self
     }
+
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn on_select(mut self, on_select: impl Fn(Option<Selection>) -> Message + 'a) -> Self {
+        self.bindings.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    pub fn on_write(mut self, on_write: impl Fn(usize, u8) -> Message + 'a) -> Self {
+        self.bindings.on_write = Some(Box::new(on_write));
+        self
+    }
+
+    pub fn on_scroll(mut self, on_scroll: impl Fn(usize) -> Message + 'a) -> Self {
+        self.bindings.on_scroll = Some(Box::new(on_scroll));
+        self
+    }
+
+    pub fn start_address(mut self, address: usize) -> Self {
+        self.bindings.start_address = Some(address);
+        self
+    }
+
+    pub fn selection(mut self, selection: Option<Selection>) -> Self {
+        self.bindings.selection = Some(selection);
+        self
+    }
 }
 
 #[cfg(feature = "iced")]
 impl<'a, Context, Theme, Message, Renderer> Widget<Message, Theme, Renderer>
-    for MemoryEditor<'a, Context, Theme>
+    for MemoryEditor<'a, Context, Message, Theme>
 where
     Renderer: renderer::Renderer + iced_core::text::Renderer<Font = iced_core::Font> + 'a,
     Theme: Catalog + iced_core::widget::text::Catalog + 'a,
@@ -152,22 +259,45 @@ where
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         _renderer: &Renderer,
-        _clipboard: &mut dyn iced_core::Clipboard,
+        clipboard: &mut dyn iced_core::Clipboard,
         shell: &mut iced_core::Shell<'_, Message>,
         _viewport: &Rectangle,
     ) {
         if iced_core::event::Status::Captured
-            == update(self.content, tree, event.clone(), layout, cursor, shell)
+            == update(
+                self.content,
+                tree,
+                event.clone(),
+                layout,
+                cursor,
+                clipboard,
+                shell,
+                &self.bindings,
+            )
         {
             shell.request_redraw();
         }
     }
 
     fn state(&self) -> widget::tree::State {
-        widget::tree::State::new(State::default())
+        widget::tree::State::new(self.bindings.state())
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        diff(self.content, tree, &self.bindings);
     }
 
-    fn diff(&self, _tree: &mut Tree) {}
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+        operation.custom(self.id.as_ref(), layout.bounds(), state);
+    }
 
     fn tag(&self) -> widget::tree::Tag {
         widget::tree::Tag::of::<State>()
@@ -180,7 +310,7 @@ where
 
 #[cfg(feature = "libcosmic")]
 impl<'a, Context, Message, Renderer> Widget<Message, cosmic::Theme, Renderer>
-    for MemoryEditor<'a, Context>
+    for MemoryEditor<'a, Context, Message>
 where
     Context: context::MemoryEditorContext + 'a,
     Renderer: iced_core::Renderer + iced_core::text::Renderer<Font = iced_core::Font> + 'a,
@@ -224,18 +354,41 @@ where
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         _renderer: &Renderer,
-        _clipboard: &mut dyn iced_core::Clipboard,
+        clipboard: &mut dyn iced_core::Clipboard,
         shell: &mut iced_core::Shell<'_, Message>,
         _viewport: &Rectangle,
     ) -> iced_core::event::Status {
-        update(self.content, tree, event, layout, cursor, shell)
+        update(
+            self.content,
+            tree,
+            event,
+            layout,
+            cursor,
+            clipboard,
+            shell,
+            &self.bindings,
+        )
     }
 
     fn state(&self) -> widget::tree::State {
-        widget::tree::State::new(State::default())
+        widget::tree::State::new(self.bindings.state())
+    }
+
+    fn diff(&mut self, tree: &mut Tree) {
+        diff(self.content, tree, &self.bindings);
     }
 
-    fn diff(&mut self, _tree: &mut Tree) {}
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        _layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation<()>,
+    ) {
+        let state = tree.state.downcast_mut::<State>();
+        operation.focusable(state, self.id.as_ref());
+        operation.custom(state, self.id.as_ref());
+    }
 
     fn tag(&self) -> widget::tree::Tag {
         widget::tree::Tag::of::<State>()
@@ -249,6 +402,36 @@ fn size() -> Size<Length> {
     }
 }
 
+fn diff<Context, Message>(
+    content: &Content<Context>,
+    tree: &mut Tree,
+    bindings: &Bindings<'_, Message>,
+) where
+    Context: context::MemoryEditorContext,
+{
+    let state = tree.state.downcast_mut::<State>();
+
+    if std::mem::take(&mut content.internal.borrow_mut().stale) {
+        update_data(content, state, options(content).row_length);
+    }
+
+    if let Some(selection) = bindings.selection {
+        if state.selection != selection {
+            state.set_selection(selection);
+            refresh_inspector(content, state);
+        }
+    }
+
+    if let Some(addr) = bindings.start_address {
+        let row_length = options(content).row_length;
+        let addr = clamp_start_address(content, state, addr, row_length);
+        if addr != state.start_address {
+            state.start_address = addr;
+            update_data(content, state, row_length);
+        }
+    }
+}
+
 fn layout<'a, Renderer, Context>(
     content: &Content<Context>,
     tree: &mut widget::Tree,
@@ -265,10 +448,15 @@ where
     let options = options(content);
 
     state.dimensions.char_height = text_line_height.to_absolute(text_size).into();
-    state.dimensions.row_count = (limits.max().height / state.dimensions.char_height).floor()
-        as usize
-        - 1
-        - if state.options_open { 4 } else { 0 };
+    state.dimensions.row_count =
+        ((limits.max().height / state.dimensions.char_height).floor() as usize).saturating_sub(
+            1 + if state.options_open {
+                OPTIONS_PANEL_LINES
+            } else {
+                0
+            } + state.tool_panel_lines()
+                + state.inspector_panel_lines(),
+        );
 
     state.text_defaults.line_height = LineHeight::default();
 
@@ -297,7 +485,8 @@ where
         state.dimensions.char_width *= 1.2;
     }
 
-    state.update_dimensions(options.row_length as f32);
+    let cell_bytes = options.cell_size.bytes();
+    state.update_dimensions(options.row_length as f32, options.cell_radix, cell_bytes);
 
     layout::Node::with_children(limits.max(), vec![])
 }
@@ -346,7 +535,7 @@ fn draw<'a, Renderer, Context>(
 
     for slice in state
         .data
-        .chunks_exact(options.row_length)
+        .chunks(options.row_length)
         .take(state.dimensions.row_count)
     {
         row(
@@ -368,17 +557,25 @@ fn draw<'a, Renderer, Context>(
         y_offset += state.dimensions.char_height;
     }
 
+    results_panel(renderer, state, style);
+    scan_list(renderer, state, style, &options);
     bottom_panel(content, tree, renderer, state, style, bounds, &options);
+    search_panel(renderer, state, style, bounds, &options);
+    scan_panel(renderer, state, style, bounds, &options);
+    inspector_panel(content, renderer, state, style, bounds, &options);
     options_panel(tree, renderer, style, layout, &options);
 }
 
+#[allow(clippy::too_many_arguments)]
 fn update<'a, Context, Message>(
     content: &Content<Context>,
     tree: &mut Tree,
     event: Event,
     layout: Layout<'_>,
     cursor: mouse::Cursor,
+    clipboard: &mut dyn iced_core::Clipboard,
     shell: &mut iced_core::Shell<'_, Message>,
+    bindings: &Bindings<'_, Message>,
 ) -> iced_core::event::Status
 where
     Context: context::MemoryEditorContext + 'a,
@@ -388,10 +585,84 @@ where
     let options = options(content);
 
     state.update_bounds(&bounds);
+    apply_operations(content, state, &options, shell, bindings);
+
+    if let Some(search) = state.search.as_mut() {
+        if state.finding_all {
+            if search.find_all(|range| content.data(range), &mut state.search_results) {
+                state.search = None;
+                state.search_status = Status::Matches(state.search_results.len());
+
+                if let Some(replacement) = state.pending_replacement.take() {
+                    let (writes, replaced, skipped) =
+                        replacement_writes(content, &state.search_results, &replacement);
+                    handle_action(
+                        content,
+                        state,
+                        Action::WriteBytes(writes),
+                        &options,
+                        shell,
+                        bindings,
+                    );
+                    state.search_status = Status::Replaced {
+                        replaced,
+                        skipped,
+                        truncated: state.search_results.len() >= MAX_RESULTS,
+                    };
+                }
+            }
+        } else {
+            match search.step(|range| content.data(range)) {
+                Progress::Pending => {}
+                Progress::Found(found) => {
+                    state.search = None;
+                    if !matches!(state.search_status, Status::Replaced { .. }) {
+                        state.search_status = Status::Found(found.start);
+                    }
+                    let selection = Selection {
+                        anchor: found.end - 1,
+                        cursor: found.start,
+                    };
+                    move_selection(content, state, selection, &options, shell, bindings);
+                }
+                Progress::NotFound => {
+                    state.search = None;
+                    if !matches!(state.search_status, Status::Replaced { .. }) {
+                        state.search_status = Status::NotFound;
+                    }
+                }
+            }
+        }
+        request_frame(shell);
+    }
+
+    if let Some(scanner) = state
+        .scanner
+        .as_mut()
+        .filter(|scanner| scanner.is_running())
+    {
+        if scanner.step(|range| content.data(range)) {
+            state.scan_status = Status::Candidates {
+                count: scanner.len(),
+                truncated: scanner.is_truncated(),
+            };
+            state.scan_scroll = 0;
+            refresh_scan_values(content, state);
+        }
+        request_frame(shell);
+    }
+
+    let committed = std::mem::take(&mut content.internal.borrow_mut().committed);
+    if let Some(on_write) = &bindings.on_write {
+        for (addr, byte) in committed {
+            shell.publish(on_write(addr, byte));
+        }
+    }
 
-    if state.data.is_empty() || content.internal.borrow_mut().context.refresh_data() {
+    let stale = std::mem::take(&mut content.internal.borrow_mut().stale);
+    if stale || state.needs_reload || content.internal.borrow_mut().context.refresh_data() {
         update_data(content, state, options.row_length);
-        return iced_core::event::Status::Captured;
+        request_frame(shell);
     }
 
     match event {
@@ -400,43 +671,7 @@ where
                 handle_mouse_interaction(content, state, cursor, bounds, &options)
             {
                 if let Some(message) = message {
-                    match message {
-                        Action::OptionsToggled => {
-                            state.options_open = !state.options_open;
-                            shell.invalidate_layout();
-                        }
-                        Action::RowLengthUpdate(len) => {
-                            write_options(
-                                content,
-                                MemoryEditorOptions {
-                                    row_length: len,
-                                    ..options
-                                },
-                            );
-                            update_data(content, state, len);
-                            shell.invalidate_layout();
-                        }
-                        Action::PreviewFormatUpdate(format) => {
-                            write_options(
-                                content,
-                                MemoryEditorOptions {
-                                    preview_data_format: format,
-                                    ..options
-                                },
-                            );
-                        }
-                        Action::ShowASCIIUpdate(show) => {
-                            write_options(
-                                content,
-                                MemoryEditorOptions {
-                                    show_ascii: show,
-                                    ..options
-                                },
-                            );
-                            shell.invalidate_layout();
-                        }
-                        _ => (),
-                    };
+                    handle_action(content, state, message, &options, shell, bindings);
                 }
                 return iced_core::event::Status::Captured;
             }
@@ -462,8 +697,14 @@ where
                 }
                 keyboard::Key::Named(keyboard::key::Named::Enter) => {
                     if let Ok(addr) = usize::from_str_radix(&state.addr_input.value, 16) {
-                        state.start_address = addr;
-                        update_data(content, state, options.row_length);
+                        handle_action(
+                            content,
+                            state,
+                            Action::StartAddressUpdate(addr),
+                            &options,
+                            shell,
+                            bindings,
+                        );
                         state.addr_input.value.clear();
                     }
                     state.addr_input.focused = false;
@@ -476,33 +717,50 @@ where
                 _ => {}
             }
         }
-        Event::Keyboard(keyboard::Event::KeyPressed { key, .. }) if state.byte_input.focused => {
+        Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
+            if state.byte_input.focused =>
+        {
+            state.edit_status = Status::Idle;
             match key {
-                keyboard::Key::Character(c) => {
-                    if state.byte_input.value.len() < 2
-                        && c.as_str()
-                            .chars()
-                            .next()
-                            .is_some_and(|ch| ch.is_ascii_hexdigit())
-                    {
-                        if let Some(ch) = c.as_str().chars().next() {
-                            state.byte_input.value.push(ch);
-                            return iced_core::event::Status::Captured;
-                        }
+                keyboard::Key::Character(c) if !modifiers.command() && !modifiers.alt() => {
+                    if state.byte_input.value.len() < VALUE_INPUT_LEN {
+                        state.byte_input.value.push_str(c.as_str());
                     }
+                    return iced_core::event::Status::Captured;
+                }
+                keyboard::Key::Named(keyboard::key::Named::Space) => {
+                    state.byte_input.value.push(' ');
+                    return iced_core::event::Status::Captured;
                 }
                 keyboard::Key::Named(keyboard::key::Named::Backspace) => {
                     state.byte_input.value.pop();
                     return iced_core::event::Status::Captured;
                 }
                 keyboard::Key::Named(keyboard::key::Named::Enter) => {
-                    if let Some(selected_addr) = state.selected_address {
-                        if let Ok(byte) = u8::from_str_radix(&state.byte_input.value, 16) {
-                            write(content, selected_addr, byte);
-                            update_data(content, state, options.row_length);
-                            state.byte_input.value.clear();
+                    let Some(selected_addr) = state.cursor_address() else {
+                        return iced_core::event::Status::Captured;
+                    };
+                    let writes = match typed_value_writes(
+                        content,
+                        selected_addr,
+                        &state.byte_input.value,
+                        &options,
+                    ) {
+                        Ok(writes) => writes,
+                        Err(status) => {
+                            state.edit_status = status;
+                            return iced_core::event::Status::Captured;
                         }
-                    }
+                    };
+                    handle_action(
+                        content,
+                        state,
+                        Action::WriteBytes(writes),
+                        &options,
+                        shell,
+                        bindings,
+                    );
+                    state.byte_input.value.clear();
                     state.byte_input.focused = false;
                     return iced_core::event::Status::Captured;
                 }
@@ -513,16 +771,213 @@ where
                 _ => {}
             }
         }
+        Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
+            if state.search_input.focused
+                || state.replace_input.focused
+                || state.epsilon_input.focused
+                || state.scan_input.focused =>
+        {
+            let Some(input) = state.focused_search_input() else {
+                return iced_core::event::Status::Ignored;
+            };
+
+            match key {
+                keyboard::Key::Character(c) if modifiers.command() => {
+                    if c.as_str() == "v" {
+                        if let Some(text) = clipboard.read(iced_core::clipboard::Kind::Standard) {
+                            input.value.push_str(text.trim());
+                        }
+                    }
+                    return iced_core::event::Status::Captured;
+                }
+                keyboard::Key::Character(c) => {
+                    input.value.push_str(c.as_str());
+                    return iced_core::event::Status::Captured;
+                }
+                keyboard::Key::Named(keyboard::key::Named::Space) => {
+                    input.value.push(' ');
+                    return iced_core::event::Status::Captured;
+                }
+                keyboard::Key::Named(keyboard::key::Named::Backspace) => {
+                    input.value.pop();
+                    return iced_core::event::Status::Captured;
+                }
+                keyboard::Key::Named(keyboard::key::Named::Enter) => {
+                    let action = if state.replace_input.focused {
+                        Action::Replace
+                    } else if state.scan_open && state.scanner.is_some() {
+                        Action::NextScan
+                    } else if state.scan_open {
+                        Action::FirstScan
+                    } else if modifiers.shift() {
+                        Action::Search(Direction::Backward)
+                    } else {
+                        Action::Search(Direction::Forward)
+                    };
+                    handle_action(content, state, action, &options, shell, bindings);
+                    return iced_core::event::Status::Captured;
+                }
+                keyboard::Key::Named(keyboard::key::Named::Escape) => {
+                    input.focused = false;
+                    return iced_core::event::Status::Captured;
+                }
+                _ => {}
+            }
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Character(c),
+            modifiers,
+            ..
+        }) if state.focused && modifiers.command() && c.as_str() == "f" => {
+            if !state.search_open {
+                handle_action(
+                    content,
+                    state,
+                    Action::SearchToggled,
+                    &options,
+                    shell,
+                    bindings,
+                );
+            }
+            state.search_input.focused = true;
+            return iced_core::event::Status::Captured;
+        }
+        Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+            state.modifiers = modifiers;
+        }
+        Event::Mouse(mouse::Event::CursorMoved { .. }) if state.dragging => {
+            if let (Some(position), Some(selection)) = (cursor.position(), state.selection) {
+                if let Some((address, _)) = address_at(state, position, bounds, &options) {
+                    let extended = extend_selection(content, state, selection, address, &options);
+                    if extended != selection {
+                        handle_action(
+                            content,
+                            state,
+                            Action::SelectionUpdate(Some(extended)),
+                            &options,
+                            shell,
+                            bindings,
+                        );
+                        return iced_core::event::Status::Captured;
+                    }
+                }
+            }
+        }
+        Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) if state.dragging => {
+            state.dragging = false;
+            return iced_core::event::Status::Captured;
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Character(c),
+            modifiers,
+            ..
+        }) if state.focused
+            && modifiers.command()
+            && matches!(c.to_lowercase().as_str(), "z" | "y") =>
+        {
+            let action = if c.to_lowercase() == "y" || modifiers.shift() {
+                Action::Redo
+            } else {
+                Action::Undo
+            };
+            handle_action(content, state, action, &options, shell, bindings);
+            return iced_core::event::Status::Captured;
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed {
+            key: keyboard::Key::Character(c),
+            modifiers,
+            ..
+        }) if state.focused && modifiers.command() && matches!(c.as_str(), "c" | "v") => {
+            let Some(selection) = state.selection else {
+                return iced_core::event::Status::Ignored;
+            };
+
+            state.edit_status = Status::Idle;
+            if c.as_str() == "c" {
+                if selection.range().len() > MAX_COPY_BYTES {
+                    state.edit_status = Status::CopyTooLarge;
+                    return iced_core::event::Status::Captured;
+                }
+                let data = content.data(selection.range());
+                clipboard.write(
+                    iced_core::clipboard::Kind::Standard,
+                    copy_text(&data, options.copy_format),
+                );
+            } else if let Some(text) = clipboard.read(iced_core::clipboard::Kind::Standard) {
+                // The pane being edited tells whether the text is hex or raw bytes
+                let bytes = match state.edit_section {
+                    Section::Hex => paste_hex(&text),
+                    Section::Ascii => Some(paste_text(&text)),
+                };
+                match bytes {
+                    Some(bytes) => handle_action(
+                        content,
+                        state,
+                        Action::UpdateBytes(selection.cursor, bytes),
+                        &options,
+                        shell,
+                        bindings,
+                    ),
+                    None => state.edit_status = Status::InvalidHex,
+                }
+            }
+            return iced_core::event::Status::Captured;
+        }
+        Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) if state.focused => {
+            return handle_key(content, state, key, modifiers, &options, shell, bindings);
+        }
+        Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Lines { y, .. },
+        }) if state.results_visible() && cursor.is_over(state.bounds.results) => {
+            let step = y.trunc().abs() as usize;
+            let max_scroll = state
+                .search_results
+                .len()
+                .saturating_sub(state.dimensions.row_count);
+            state.results_scroll = if y.is_sign_negative() {
+                state.results_scroll.saturating_sub(step)
+            } else {
+                (state.results_scroll + step).min(max_scroll)
+            };
+            return iced_core::event::Status::Captured;
+        }
+        Event::Mouse(mouse::Event::WheelScrolled {
+            delta: mouse::ScrollDelta::Lines { y, .. },
+        }) if state.scan_visible() && cursor.is_over(state.bounds.scan_list) => {
+            let step = y.trunc().abs() as usize;
+            let max_scroll = state
+                .scanner
+                .as_ref()
+                .map_or(0, |scanner| scanner.len())
+                .saturating_sub(state.dimensions.row_count);
+            state.scan_scroll = if y.is_sign_negative() {
+                state.scan_scroll.saturating_sub(step)
+            } else {
+                (state.scan_scroll + step).min(max_scroll)
+            };
+            refresh_scan_values(content, state);
+            return iced_core::event::Status::Captured;
+        }
         Event::Mouse(mouse::Event::WheelScrolled {
             delta: mouse::ScrollDelta::Lines { y, .. },
         }) => {
-            let step = y.trunc() * options.row_length as f32;
-            state.start_address = if step.is_sign_negative() {
-                state.start_address.saturating_sub(step.abs() as usize)
+            let range = address_range(content);
+            let step = y.trunc().abs() as usize * options.row_length;
+            let start_address = if y.is_sign_negative() {
+                state.start_address.saturating_sub(step).max(range.start)
             } else {
-                state.start_address + step as usize
+                let max_start = max_start_address(state, options.row_length, &range);
+                let available = max_start.saturating_sub(state.start_address);
+                state.start_address + step.min(available - available % options.row_length)
             };
-            update_data(content, state, options.row_length);
+            handle_action(
+                content,
+                state,
+                Action::StartAddressUpdate(start_address),
+                &options,
+                shell,
+                bindings,
+            );
             return iced_core::event::Status::Captured;
         }
         _ => (),
@@ -530,231 +985,2272 @@ where
     iced_core::event::Status::Ignored
 }
 
-fn options<Context: MemoryEditorContext>(content: &Content<Context>) -> MemoryEditorOptions {
-    content.internal.borrow().context.options()
+fn handle_key<Context: MemoryEditorContext, Message>(
+    content: &Content<Context>,
+    state: &mut State,
+    key: keyboard::Key,
+    modifiers: keyboard::Modifiers,
+    options: &MemoryEditorOptions,
+    shell: &mut iced_core::Shell<'_, Message>,
+    bindings: &Bindings<'_, Message>,
+) -> iced_core::event::Status {
+    if let keyboard::Key::Named(named) = key {
+        if named == keyboard::key::Named::Escape && !state.edit_digits.is_empty() {
+            state.edit_digits.clear();
+            return iced_core::event::Status::Captured;
+        }
+
+        let range = address_range(content);
+        if let Some(addr) = navigation_target(state, named, modifiers, options, &range) {
+            let selection = match state.selection {
+                Some(selection) if modifiers.shift() => {
+                    extend_selection(content, state, selection, addr, options)
+                }
+                _ => Selection::new(addr),
+            };
+            move_selection(content, state, selection, options, shell, bindings);
+            return iced_core::event::Status::Captured;
+        }
+    }
+
+    if modifiers.command() || modifiers.alt() {
+        return iced_core::event::Status::Ignored;
+    }
+
+    let Some(selected_addr) = state.cursor_address() else {
+        return iced_core::event::Status::Ignored;
+    };
+
+    let typed = match &key {
+        keyboard::Key::Character(c) => c.as_str().chars().next(),
+        keyboard::Key::Named(keyboard::key::Named::Space) => Some(' '),
+        _ => None,
+    };
+
+    let cell = match state.edit_section {
+        Section::Hex => cell_range(state, selected_addr, options, &address_range(content)),
+        Section::Ascii => selected_addr..selected_addr + 1,
+    };
+
+    let action = match (state.edit_section, typed) {
+        (Section::Hex, Some(ch)) if ch.is_digit(options.cell_radix.radix()) => {
+            if !cell
+                .clone()
+                .all(|addr| content.internal.borrow().context.can_write(addr))
+            {
+                return iced_core::event::Status::Ignored;
+            }
+            state.edit_digits.push(ch);
+            if state.edit_digits.len() < options.cell_radix.digits(cell.len()) {
+                return iced_core::event::Status::Captured;
+            }
+            let bytes = u64::from_str_radix(&state.edit_digits, options.cell_radix.radix())
+                .ok()
+                .and_then(|word| {
+                    value::encode(
+                        Value::Unsigned(word.into()),
+                        word_format(cell.len()),
+                        options.endianness,
+                    )
+                });
+            state.edit_digits.clear();
+            match bytes {
+                Some(bytes) => Action::WriteBytes(cell.clone().zip(bytes).collect()),
+                None => return iced_core::event::Status::Captured,
+            }
+        }
+        (Section::Ascii, Some(ch)) if ch == ' ' || ch.is_ascii_graphic() => {
+            if !content.internal.borrow().context.can_write(selected_addr) {
+                return iced_core::event::Status::Ignored;
+            }
+            Action::UpdateByte(selected_addr, ch as u8)
+        }
+        _ => return iced_core::event::Status::Ignored,
+    };
+
+    state.edit_digits.clear();
+    handle_action(content, state, action, options, shell, bindings);
+    if let Some(next_addr) = next_writable_address(content, state, cell.end - 1, options.row_length)
+    {
+        move_selection(
+            content,
+            state,
+            Selection::new(next_addr),
+            options,
+            shell,
+            bindings,
+        );
+    }
+
+    iced_core::event::Status::Captured
 }
 
-fn update_data<Context: MemoryEditorContext>(
+/// Applies what `scroll_to` and `select` requested through the same actions as user input,
+/// so the start address and selection are clamped, reloaded and reported.
+fn apply_operations<Context: MemoryEditorContext, Message>(
     content: &Content<Context>,
     state: &mut State,
-    len: usize,
+    options: &MemoryEditorOptions,
+    shell: &mut iced_core::Shell<'_, Message>,
+    bindings: &Bindings<'_, Message>,
 ) {
-    state.data = content.internal.borrow().context.data(Range::<usize> {
-        start: state.start_address,
-        end: state.start_address + state.dimensions.row_count * len,
-    });
-}
+    if let Some(addr) = state.pending_scroll.take() {
+        handle_action(
+            content,
+            state,
+            Action::StartAddressUpdate(addr),
+            options,
+            shell,
+            bindings,
+        );
+        request_frame(shell);
+    }
 
-fn write<Context: MemoryEditorContext>(content: &Content<Context>, addr: usize, data: u8) {
-    content.internal.borrow_mut().context.write(addr, data);
+    if let Some(range) = state.pending_selection.take() {
+        let address_range = address_range(content);
+        let range = range.start.max(address_range.start)..range.end.min(address_range.end);
+        if let Some(selection) = Selection::from_range(range) {
+            handle_action(
+                content,
+                state,
+                Action::SelectionUpdate(Some(selection)),
+                options,
+                shell,
+                bindings,
+            );
+        }
+        request_frame(shell);
+    }
 }
 
-fn write_options<Context: MemoryEditorContext>(
+fn move_selection<Context: MemoryEditorContext, Message>(
     content: &Content<Context>,
-    options: MemoryEditorOptions,
+    state: &mut State,
+    selection: Selection,
+    options: &MemoryEditorOptions,
+    shell: &mut iced_core::Shell<'_, Message>,
+    bindings: &Bindings<'_, Message>,
 ) {
-    content.internal.borrow_mut().context.write_options(options);
+    let start_address = start_address_showing(state, selection.cursor, options.row_length);
+    handle_action(
+        content,
+        state,
+        Action::SelectionUpdate(Some(selection)),
+        options,
+        shell,
+        bindings,
+    );
+    handle_action(
+        content,
+        state,
+        Action::StartAddressUpdate(start_address),
+        options,
+        shell,
+        bindings,
+    );
 }
 
-fn handle_mouse_interaction<Context: MemoryEditorContext>(
+fn handle_action<Context: MemoryEditorContext, Message>(
     content: &Content<Context>,
     state: &mut State,
-    cursor: Cursor,
-    bounds: Rectangle,
+    action: Action,
     options: &MemoryEditorOptions,
-) -> (bool, Option<Action>) {
-    if state.addr_input.focused {
-        state.addr_input.focused = false;
+    shell: &mut iced_core::Shell<'_, Message>,
+    bindings: &Bindings<'_, Message>,
+) {
+    match action {
+        Action::OptionsToggled => {
+            state.options_open = !state.options_open;
+            shell.invalidate_layout();
+        }
+        Action::SearchToggled => {
+            state.search_open = !state.search_open;
+            state.search_input.focused = state.search_open;
+            if state.search_open {
+                state.scan_open = false;
+            } else {
+                state.search = None;
+                state.search_results.clear();
+            }
+            shell.invalidate_layout();
+        }
+        Action::InspectorToggled => {
+            state.inspector_open = !state.inspector_open;
+            refresh_inspector(content, state);
+            shell.invalidate_layout();
+        }
+        Action::ScanToggled => {
+            state.scan_open = !state.scan_open;
+            state.scan_input.focused = state.scan_open;
+            if state.scan_open {
+                state.search_open = false;
+                state.search = None;
+                state.search_results.clear();
+            }
+            refresh_scan_values(content, state);
+            shell.invalidate_layout();
+        }
+        Action::FirstScan => {
+            let text = state.scan_input.value.trim();
+            let target = if text.is_empty() {
+                None
+            } else if let Some(value) = value::parse(text, state.value_format) {
+                Some(value)
+            } else {
+                state.scan_status = Status::Invalid;
+                return;
+            };
+            state.scanner = Some(Scanner::first(
+                state.value_format,
+                options.endianness,
+                state.aligned,
+                address_range(content),
+                target,
+                epsilon(state),
+            ));
+            state.scan_values.clear();
+            state.scan_status = Status::Scanning;
+            request_frame(shell);
+        }
+        Action::NextScan => {
+            let epsilon = epsilon(state);
+            let Some(scanner) = state
+                .scanner
+                .as_mut()
+                .filter(|scanner| !scanner.is_running())
+            else {
+                return;
+            };
+            let target = value::parse(&state.scan_input.value, scanner.format());
+            if state.scan_filter == ScanFilter::Equals && target.is_none() {
+                state.scan_status = Status::Invalid;
+                return;
+            }
+            scanner.next(state.scan_filter, target, epsilon);
+            state.scan_status = Status::Scanning;
+            request_frame(shell);
+        }
+        Action::ResetScan => {
+            state.scanner = None;
+            state.scan_values.clear();
+            state.scan_scroll = 0;
+            state.scan_status = Status::Idle;
+        }
+        Action::ScanResultSelected(idx) => {
+            let Some((addr, len)) = state.scanner.as_ref().and_then(|scanner| {
+                scanner
+                    .get(idx)
+                    .map(|candidate| (candidate.address, scanner.format().size()))
+            }) else {
+                return;
+            };
+            reveal(content, state, addr, len, options, shell, bindings);
+        }
+        Action::Search(direction) => {
+            let Some(pattern) = search_pattern(state, options) else {
+                state.search = None;
+                state.search_status = Status::Invalid;
+                return;
+            };
+            let from = match (state.cursor_address(), direction) {
+                (Some(cursor), Direction::Forward) => cursor + 1,
+                (Some(cursor), Direction::Backward) => cursor,
+                (None, _) => state.start_address,
+            };
+            state.finding_all = false;
+            state.search = Some(Search::new(
+                pattern,
+                direction,
+                address_range(content),
+                from,
+            ));
+            state.search_status = Status::Searching;
+            request_frame(shell);
+        }
+        Action::Replace => {
+            let (Some(pattern), Some(replacement)) = (
+                search_pattern(state, options),
+                search_replacement(state, options),
+            ) else {
+                state.search = None;
+                state.search_status = Status::Invalid;
+                return;
+            };
+
+            let mut replaced_status = None;
+            if let Some(cursor) = state.cursor_address() {
+                let end = (cursor + pattern.len()).min(address_range(content).end);
+                if pattern.matches(&content.data(cursor..end)) {
+                    let (writes, replaced, skipped) =
+                        replacement_writes(content, &[cursor], &replacement);
+                    replaced_status = Some(Status::Replaced {
+                        replaced,
+                        skipped,
+                        truncated: false,
+                    });
+                    handle_action(
+                        content,
+                        state,
+                        Action::WriteBytes(writes),
+                        options,
+                        shell,
+                        bindings,
+                    );
+                }
+            }
+
+            handle_action(
+                content,
+                state,
+                Action::Search(Direction::Forward),
+                options,
+                shell,
+                bindings,
+            );
+            // Keep reporting the replacement while moving on to the next match
+            if let Some(status) = replaced_status {
+                state.search_status = status;
+            }
+        }
+        Action::ReplaceAll => {
+            let Some(replacement) = search_replacement(state, options) else {
+                state.search = None;
+                state.search_status = Status::Invalid;
+                return;
+            };
+            handle_action(content, state, Action::FindAll, options, shell, bindings);
+            if state.search.is_some() {
+                state.pending_replacement = Some(replacement);
+            }
+        }
+        Action::FindAll => {
+            state.pending_replacement = None;
+            state.search_results.clear();
+            state.results_scroll = 0;
+            let Some(pattern) = search_pattern(state, options) else {
+                state.search = None;
+                state.search_status = Status::Invalid;
+                return;
+            };
+            let range = address_range(content);
+            state.finding_all = true;
+            state.search_match_len = pattern.len();
+            state.search = Some(Search::new(
+                pattern,
+                Direction::Forward,
+                range.clone(),
+                range.start,
+            ));
+            state.search_status = Status::Searching;
+            request_frame(shell);
+        }
+        Action::SearchResultSelected(idx) => {
+            let Some(&addr) = state.search_results.get(idx) else {
+                return;
+            };
+            let len = state.search_match_len;
+            reveal(content, state, addr, len, options, shell, bindings);
+        }
+        Action::RowLengthUpdate(len) => {
+            write_options(
+                content,
+                MemoryEditorOptions {
+                    row_length: len,
+                    ..options.clone()
+                },
+            );
+            update_data(content, state, len);
+            shell.invalidate_layout();
+        }
+        Action::PreviewFormatUpdate(format) => {
+            write_options(
+                content,
+                MemoryEditorOptions {
+                    preview_data_format: format,
+                    ..options.clone()
+                },
+            );
+        }
+        Action::ShowASCIIUpdate(show) => {
+            write_options(
+                content,
+                MemoryEditorOptions {
+                    show_ascii: show,
+                    ..options.clone()
+                },
+            );
+            shell.invalidate_layout();
+        }
+        Action::SelectionUpdate(selection) if bindings.selection.is_some() => {
+            if let Some(on_select) = &bindings.on_select {
+                shell.publish(on_select(selection));
+            }
+        }
+        Action::StartAddressUpdate(addr) if bindings.start_address.is_some() => {
+            let addr = clamp_start_address(content, state, addr, options.row_length);
+            if addr != state.start_address {
+                if let Some(on_scroll) = &bindings.on_scroll {
+                    shell.publish(on_scroll(addr));
+                }
+            }
+        }
+        Action::CopyFormatUpdate(format) => {
+            write_options(
+                content,
+                MemoryEditorOptions {
+                    copy_format: format,
+                    ..options.clone()
+                },
+            );
+        }
+        Action::CellSizeUpdate(cell_size) => {
+            state.edit_digits.clear();
+            write_options(
+                content,
+                MemoryEditorOptions {
+                    cell_size,
+                    ..options.clone()
+                },
+            );
+            shell.invalidate_layout();
+        }
+        Action::CellRadixUpdate(cell_radix) => {
+            state.edit_digits.clear();
+            write_options(
+                content,
+                MemoryEditorOptions {
+                    cell_radix,
+                    ..options.clone()
+                },
+            );
+            shell.invalidate_layout();
+        }
+        Action::FloatFormatUpdate(float_format) => {
+            write_options(
+                content,
+                MemoryEditorOptions {
+                    float_format,
+                    ..options.clone()
+                },
+            );
+        }
+        Action::FloatPrecisionUpdate(float_precision) => {
+            write_options(
+                content,
+                MemoryEditorOptions {
+                    float_precision,
+                    ..options.clone()
+                },
+            );
+        }
+        Action::EndiannessUpdate(endianness) => {
+            write_options(
+                content,
+                MemoryEditorOptions {
+                    endianness,
+                    ..options.clone()
+                },
+            );
+        }
+        Action::UpdateBytes(addr, bytes) => {
+            let range = address_range(content);
+            let written: Vec<(usize, u8)> = (addr..range.end)
+                .zip(bytes)
+                .filter_map(|(addr, byte)| Some((addr, byte?)))
+                .filter(|(addr, _)| content.internal.borrow().context.can_write(*addr))
+                .collect();
+            handle_action(
+                content,
+                state,
+                Action::WriteBytes(written),
+                options,
+                shell,
+                bindings,
+            );
+        }
+        Action::WriteBytes(written) => {
+            let committed = content.write(&written);
+            update_data(content, state, options.row_length);
+            if let Some(on_write) = bindings.on_write.as_ref().filter(|_| committed) {
+                for (addr, byte) in written {
+                    shell.publish(on_write(addr, byte));
+                }
+            }
+        }
+        Action::Undo | Action::Redo => {
+            let edits = if action == Action::Undo {
+                content.undo()
+            } else {
+                content.redo()
+            };
+            let Some(edits) = edits else {
+                return;
+            };
+            update_data(content, state, options.row_length);
+            if let Some(on_write) = &bindings.on_write {
+                for edit in edits {
+                    let value = if action == Action::Undo {
+                        edit.old
+                    } else {
+                        edit.new
+                    };
+                    shell.publish(on_write(edit.address, value));
+                }
+            }
+        }
+        Action::UpdateByte(addr, byte) => {
+            let committed = content.write(&[(addr, byte)]);
+            update_data(content, state, options.row_length);
+            if let Some(on_write) = bindings.on_write.as_ref().filter(|_| committed) {
+                shell.publish(on_write(addr, byte));
+            }
+        }
+        Action::SelectionUpdate(selection) => {
+            state.set_selection(selection);
+            refresh_inspector(content, state);
+            if let Some(on_select) = &bindings.on_select {
+                shell.publish(on_select(selection));
+            }
+        }
+        Action::StartAddressUpdate(addr) => {
+            let previous = state.start_address;
+            state.start_address = addr;
+            update_data(content, state, options.row_length);
+            if state.start_address != previous {
+                if let Some(on_scroll) = &bindings.on_scroll {
+                    shell.publish(on_scroll(state.start_address));
+                }
+            }
+        }
+    }
+}
+
+fn options<Context: MemoryEditorContext>(content: &Content<Context>) -> MemoryEditorOptions {
+    content.internal.borrow().context.options()
+}
+
+fn address_range<Context: MemoryEditorContext>(content: &Content<Context>) -> Range<usize> {
+    content.internal.borrow().context.address_range()
+}
+
+/// The start of the row showing the end of the range at the bottom, rows being
+/// aligned to the start of the range.
+fn max_start_address(state: &State, row_length: usize, range: &Range<usize>) -> usize {
+    let rows = range.len().div_ceil(row_length);
+    range.start + rows.saturating_sub(state.dimensions.row_count.max(1)) * row_length
+}
+
+fn clamp_start_address<Context: MemoryEditorContext>(
+    content: &Content<Context>,
+    state: &State,
+    addr: usize,
+    row_length: usize,
+) -> usize {
+    let range = address_range(content);
+    let addr = addr.clamp(range.start, max_start_address(state, row_length, &range));
+    addr - (addr - range.start) % row_length
+}
+
+fn update_data<Context: MemoryEditorContext>(
+    content: &Content<Context>,
+    state: &mut State,
+    len: usize,
+) {
+    let range = address_range(content);
+    state.start_address = clamp_start_address(content, state, state.start_address, len);
+
+    let end = (state.start_address + state.dimensions.row_count * len).min(range.end);
+    state.data = if state.start_address < end {
+        content.data(Range::<usize> {
+            start: state.start_address,
+            end,
+        })
+    } else {
+        Vec::new()
+    };
+    state.modified = (state.start_address..state.start_address + state.data.len())
+        .map(|addr| content.is_pending(addr))
+        .collect();
+    state.needs_reload = false;
+    refresh_scan_values(content, state);
+    refresh_inspector(content, state);
+}
+
+/// Selects `len` bytes at `addr` and scrolls its row to the top.
+fn reveal<Context: MemoryEditorContext, Message>(
+    content: &Content<Context>,
+    state: &mut State,
+    addr: usize,
+    len: usize,
+    options: &MemoryEditorOptions,
+    shell: &mut iced_core::Shell<'_, Message>,
+    bindings: &Bindings<'_, Message>,
+) {
+    let selection = Selection {
+        anchor: addr + len.max(1) - 1,
+        cursor: addr,
+    };
+    handle_action(
+        content,
+        state,
+        Action::SelectionUpdate(Some(selection)),
+        options,
+        shell,
+        bindings,
+    );
+    handle_action(
+        content,
+        state,
+        Action::StartAddressUpdate(addr),
+        options,
+        shell,
+        bindings,
+    );
+}
+
+/// Re-reads the current value of the candidates shown in the scan list.
+fn refresh_scan_values<Context: MemoryEditorContext>(
+    content: &Content<Context>,
+    state: &mut State,
+) {
+    state.scan_values = match &state.scanner {
+        Some(scanner) if state.scan_open && !scanner.is_running() => scanner
+            .candidates(state.scan_scroll)
+            .take(state.dimensions.row_count)
+            .map(|candidate| {
+                scanner.value_at(&content.data(scanner.value_range(candidate.address)))
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+}
+
+/// Re-reads the bytes decoded by the inspector at the cursor.
+fn refresh_inspector<Context: MemoryEditorContext>(content: &Content<Context>, state: &mut State) {
+    state.inspector_data = match state.cursor_address() {
+        Some(addr) if state.inspector_open => {
+            let end = address_range(content).end.min(addr + INSPECTOR_BYTES);
+            content.data(addr..end.max(addr))
+        }
+        _ => Vec::new(),
+    };
+}
+
+fn epsilon(state: &State) -> f64 {
+    state.epsilon_input.value.trim().parse().unwrap_or(0.0)
+}
+
+fn search_pattern(state: &State, options: &MemoryEditorOptions) -> Option<Pattern> {
+    match state.search_mode {
+        SearchMode::Hex => Pattern::parse_hex(&state.search_input.value),
+        SearchMode::Text => Pattern::parse_text(
+            &state.search_input.value,
+            state.text_encoding,
+            state.match_case,
+        ),
+        SearchMode::Value => Pattern::parse_value(
+            &state.search_input.value,
+            state.value_format,
+            options.endianness,
+            epsilon(state),
+            state.aligned,
+        ),
+    }
+}
+
+fn search_replacement(state: &State, options: &MemoryEditorOptions) -> Option<Vec<Option<u8>>> {
+    let text = &state.replace_input.value;
+    match state.search_mode {
+        SearchMode::Hex => match Pattern::parse_hex(text)? {
+            Pattern::Bytes(bytes) => Some(bytes),
+            _ => None,
+        },
+        SearchMode::Text => state
+            .text_encoding
+            .encode_str(text)
+            .map(|bytes| bytes.into_iter().map(Some).collect()),
+        SearchMode::Value => value::encode(
+            value::parse(text, state.value_format)?,
+            state.value_format,
+            options.endianness,
+        )
+        .map(|bytes| bytes.into_iter().map(Some).collect()),
+    }
+}
+
+/// Overwrites each match with the replacement, `None` bytes are left untouched.
+/// Matches overlapping a replaced one are ignored, and matches with any
+/// non-writable byte are skipped.
+fn replacement_writes<Context: MemoryEditorContext>(
+    content: &Content<Context>,
+    matches: &[usize],
+    replacement: &[Option<u8>],
+) -> (Vec<(usize, u8)>, usize, usize) {
+    let range = address_range(content);
+    let internal = content.internal.borrow();
+    let mut writes = Vec::new();
+    let (mut replaced, mut skipped) = (0, 0);
+    let mut next_free = range.start;
+
+    for &start in matches {
+        // Overlaps a match that was just replaced
+        if start < next_free {
+            skipped += 1;
+            continue;
+        }
+
+        let bytes: Vec<(usize, u8)> = replacement
+            .iter()
+            .enumerate()
+            .filter_map(|(offset, byte)| byte.map(|byte| (start + offset, byte)))
+            .collect();
+
+        if start + replacement.len() <= range.end
+            && bytes
+                .iter()
+                .all(|(addr, _)| internal.context.can_write(*addr))
+        {
+            writes.extend(bytes);
+            replaced += 1;
+            next_free = start + replacement.len();
+        } else {
+            skipped += 1;
+        }
+    }
+
+    (writes, replaced, skipped)
+}
+
+/// Encodes `text` in the preview format at `addr`, or the status telling why it
+/// doesn't parse, doesn't fit the format or can't be written.
+fn typed_value_writes<Context: MemoryEditorContext>(
+    content: &Content<Context>,
+    addr: usize,
+    text: &str,
+    options: &MemoryEditorOptions,
+) -> Result<Vec<(usize, u8)>, Status> {
+    let format = options.preview_data_format;
+    let value = value::parse(text, format).ok_or_else(|| {
+        // A number that only parses at a wider width is out of range
+        match value::parse(text, PreviewDataFormat::I128)
+            .or_else(|| value::parse(text, PreviewDataFormat::U128))
+        {
+            Some(_) => Status::OutOfRange,
+            None => Status::InvalidValue,
+        }
+    })?;
+    let bytes = value::encode(value, format, options.endianness).ok_or(Status::OutOfRange)?;
+    let end = addr
+        .checked_add(bytes.len())
+        .filter(|&end| end <= address_range(content).end)
+        .ok_or(Status::NotWritable)?;
+    let internal = content.internal.borrow();
+
+    if !(addr..end).all(|addr| internal.context.can_write(addr)) {
+        return Err(Status::NotWritable);
+    }
+    Ok((addr..).zip(bytes).collect())
+}
+
+fn request_frame<Message>(shell: &mut iced_core::Shell<'_, Message>) {
+    #[cfg(feature = "iced")]
+    shell.request_redraw();
+    #[cfg(feature = "libcosmic")]
+    shell.request_redraw(iced_core::window::RedrawRequest::NextFrame);
+}
+
+fn write_options<Context: MemoryEditorContext>(
+    content: &Content<Context>,
+    options: MemoryEditorOptions,
+) {
+    content.internal.borrow_mut().context.write_options(options);
+}
+
+fn handle_mouse_interaction<Context: MemoryEditorContext>(
+    content: &Content<Context>,
+    state: &mut State,
+    cursor: Cursor,
+    bounds: Rectangle,
+    options: &MemoryEditorOptions,
+) -> (bool, Option<Action>) {
+    if state.addr_input.focused {
+        state.addr_input.focused = false;
     }
 
     if state.byte_input.focused {
         state.byte_input.focused = false;
     }
 
-    if cursor.position().is_none() {
+    if state.search_input.focused {
+        state.search_input.focused = false;
+    }
+
+    if state.epsilon_input.focused {
+        state.epsilon_input.focused = false;
+    }
+
+    if state.replace_input.focused {
+        state.replace_input.focused = false;
+    }
+
+    if state.scan_input.focused {
+        state.scan_input.focused = false;
+    }
+
+    if cursor.position().is_none() || !cursor.is_over(bounds) {
+        state.focused = false;
         return (false, None);
     }
 
     let position = cursor.position().unwrap();
+    state.focused = true;
 
     if cursor.is_over(state.bounds.options) {
         return (true, Some(Action::OptionsToggled));
     }
 
-    if cursor.is_over(state.bounds.addr_input) {
-        state.addr_input.focused = true;
-        state.addr_input.value.clear();
-        return (true, None);
+    if cursor.is_over(state.bounds.search_toggle) {
+        return (true, Some(Action::SearchToggled));
     }
 
-    if let Some(selected_addr) = state.selected_address {
-        if cursor.is_over(state.bounds.byte_input)
-            && content.internal.borrow().context.can_write(selected_addr)
-        {
-            state.byte_input.focused = true;
-            state.byte_input.value.clear();
+    if cursor.is_over(state.bounds.scan_toggle) {
+        return (true, Some(Action::ScanToggled));
+    }
+
+    if cursor.is_over(state.bounds.inspector_toggle) {
+        return (true, Some(Action::InspectorToggled));
+    }
+
+    if state.scan_open {
+        if cursor.is_over(state.bounds.scan_input) {
+            state.scan_input.focused = true;
             return (true, None);
         }
-    }
 
-    if state.options_open {
-        if cursor.is_over(state.bounds.show_ascii_checkbox) {
-            return (true, Some(Action::ShowASCIIUpdate(!options.show_ascii)));
+        if cursor.is_over(state.bounds.scan_first) {
+            return (true, Some(Action::FirstScan));
         }
 
-        if cursor.is_over(state.bounds.prev_format) {
-            return (
-                true,
-                Some(Action::PreviewFormatUpdate(options.previous_data_format())),
-            );
+        if cursor.is_over(state.bounds.scan_next) {
+            return (true, Some(Action::NextScan));
+        }
+
+        if cursor.is_over(state.bounds.scan_reset) {
+            return (true, Some(Action::ResetScan));
         }
 
-        if cursor.is_over(state.bounds.next_format) {
+        if cursor.is_over(state.bounds.scan_filter.prev) {
+            state.scan_filter = state.scan_filter.previous();
+            return (true, None);
+        }
+
+        if cursor.is_over(state.bounds.scan_filter.next) {
+            state.scan_filter = state.scan_filter.next();
+            return (true, None);
+        }
+
+        if state.scan_visible() && cursor.is_over(state.bounds.scan_list) {
+            let line =
+                ((position.y - state.bounds.scan_list.y) / state.dimensions.char_height) as usize;
             return (
                 true,
-                Some(Action::PreviewFormatUpdate(options.next_data_format())),
+                Some(Action::ScanResultSelected(state.scan_scroll + line)),
             );
         }
+    }
+
+    if state.search_open {
+        if cursor.is_over(state.bounds.search_input) {
+            state.search_input.focused = true;
+            return (true, None);
+        }
+
+        if cursor.is_over(state.bounds.search_nav.prev) {
+            return (true, Some(Action::Search(Direction::Backward)));
+        }
+
+        if cursor.is_over(state.bounds.search_nav.next) {
+            return (true, Some(Action::Search(Direction::Forward)));
+        }
+
+        if cursor.is_over(state.bounds.find_all) {
+            return (true, Some(Action::FindAll));
+        }
+
+        if cursor.is_over(state.bounds.replace_input) {
+            state.replace_input.focused = true;
+            return (true, None);
+        }
+
+        if cursor.is_over(state.bounds.replace) {
+            return (true, Some(Action::Replace));
+        }
+
+        if cursor.is_over(state.bounds.replace_all) {
+            return (true, Some(Action::ReplaceAll));
+        }
 
-        if cursor.is_over(state.bounds.prev_row_length) {
+        if state.results_visible() && cursor.is_over(state.bounds.results) {
+            let line =
+                ((position.y - state.bounds.results.y) / state.dimensions.char_height) as usize;
             return (
                 true,
-                Some(Action::RowLengthUpdate((options.row_length - 8).max(8))),
+                Some(Action::SearchResultSelected(state.results_scroll + line)),
             );
         }
 
-        if cursor.is_over(state.bounds.next_row_length) {
-            return (true, Some(Action::RowLengthUpdate(options.row_length + 8)));
+        if cursor.is_over(state.bounds.search_mode.prev) {
+            state.search_mode = state.search_mode.previous();
+            return (true, None);
         }
-    }
 
-    let row_index = ((position.y - bounds.y) / state.dimensions.char_height).trunc() as usize;
-    if row_index >= state.dimensions.row_count {
-        state.selected_address = None;
-        state.text.value_text.clear();
-        return (true, None);
+        if cursor.is_over(state.bounds.search_mode.next) {
+            state.search_mode = state.search_mode.next();
+            return (true, None);
+        }
+
+        if state.search_mode == SearchMode::Text {
+            if cursor.is_over(state.bounds.text_encoding.prev) {
+                state.text_encoding = state.text_encoding.previous();
+                return (true, None);
+            }
+
+            if cursor.is_over(state.bounds.text_encoding.next) {
+                state.text_encoding = state.text_encoding.next();
+                return (true, None);
+            }
+
+            if cursor.is_over(state.bounds.match_case_checkbox) {
+                state.match_case = !state.match_case;
+                return (true, None);
+            }
+        }
+    }
+
+    if (state.search_open && state.search_mode == SearchMode::Value) || state.scan_open {
+        if cursor.is_over(state.bounds.value_format.prev) {
+            state.value_format = state.value_format.previous();
+            return (true, None);
+        }
+
+        if cursor.is_over(state.bounds.value_format.next) {
+            state.value_format = state.value_format.next();
+            return (true, None);
+        }
+
+        if cursor.is_over(state.bounds.aligned_checkbox) {
+            state.aligned = !state.aligned;
+            return (true, None);
+        }
+
+        if cursor.is_over(state.bounds.value_endianness.prev) {
+            return (
+                true,
+                Some(Action::EndiannessUpdate(options.endianness.previous())),
+            );
+        }
+
+        if cursor.is_over(state.bounds.value_endianness.next) {
+            return (
+                true,
+                Some(Action::EndiannessUpdate(options.endianness.next())),
+            );
+        }
+
+        if state.value_format.is_float() && cursor.is_over(state.bounds.epsilon_input) {
+            state.epsilon_input.focused = true;
+            return (true, None);
+        }
+    }
+
+    if cursor.is_over(state.bounds.addr_input) {
+        state.addr_input.focused = true;
+        state.addr_input.value.clear();
+        return (true, None);
+    }
+
+    if let Some(selected_addr) = state.cursor_address() {
+        if cursor.is_over(state.bounds.byte_input)
+            && content.internal.borrow().context.can_write(selected_addr)
+        {
+            state.byte_input.focused = true;
+            state.byte_input.value.clear();
+            return (true, None);
+        }
+    }
+
+    if state.options_open {
+        if cursor.is_over(state.bounds.show_ascii_checkbox) {
+            return (true, Some(Action::ShowASCIIUpdate(!options.show_ascii)));
+        }
+
+        if cursor.is_over(state.bounds.format.prev) {
+            return (
+                true,
+                Some(Action::PreviewFormatUpdate(options.previous_data_format())),
+            );
+        }
+
+        if cursor.is_over(state.bounds.format.next) {
+            return (
+                true,
+                Some(Action::PreviewFormatUpdate(options.next_data_format())),
+            );
+        }
+
+        if cursor.is_over(state.bounds.row_length.prev) {
+            return (
+                true,
+                Some(Action::RowLengthUpdate((options.row_length - 8).max(8))),
+            );
+        }
+
+        if cursor.is_over(state.bounds.row_length.next) {
+            return (true, Some(Action::RowLengthUpdate(options.row_length + 8)));
+        }
+
+        if cursor.is_over(state.bounds.cell_radix.prev) {
+            return (
+                true,
+                Some(Action::CellRadixUpdate(options.cell_radix.previous())),
+            );
+        }
+
+        if cursor.is_over(state.bounds.cell_radix.next) {
+            return (
+                true,
+                Some(Action::CellRadixUpdate(options.cell_radix.next())),
+            );
+        }
+
+        if cursor.is_over(state.bounds.copy_format.prev) {
+            return (
+                true,
+                Some(Action::CopyFormatUpdate(options.copy_format.previous())),
+            );
+        }
+
+        if cursor.is_over(state.bounds.copy_format.next) {
+            return (
+                true,
+                Some(Action::CopyFormatUpdate(options.copy_format.next())),
+            );
+        }
+
+        if cursor.is_over(state.bounds.endianness.prev) {
+            return (
+                true,
+                Some(Action::EndiannessUpdate(options.endianness.previous())),
+            );
+        }
+
+        if cursor.is_over(state.bounds.endianness.next) {
+            return (
+                true,
+                Some(Action::EndiannessUpdate(options.endianness.next())),
+            );
+        }
+
+        if cursor.is_over(state.bounds.cell_size.prev) {
+            return (
+                true,
+                Some(Action::CellSizeUpdate(options.cell_size.previous())),
+            );
+        }
+
+        if cursor.is_over(state.bounds.cell_size.next) {
+            return (true, Some(Action::CellSizeUpdate(options.cell_size.next())));
+        }
+
+        if cursor.is_over(state.bounds.float_format.prev) {
+            return (
+                true,
+                Some(Action::FloatFormatUpdate(options.float_format.previous())),
+            );
+        }
+
+        if cursor.is_over(state.bounds.float_format.next) {
+            return (
+                true,
+                Some(Action::FloatFormatUpdate(options.float_format.next())),
+            );
+        }
+
+        if options.float_format.has_precision() {
+            if cursor.is_over(state.bounds.float_precision.prev) {
+                return (
+                    true,
+                    Some(Action::FloatPrecisionUpdate(
+                        options.float_precision.saturating_sub(1),
+                    )),
+                );
+            }
+
+            if cursor.is_over(state.bounds.float_precision.next) {
+                return (
+                    true,
+                    Some(Action::FloatPrecisionUpdate(
+                        (options.float_precision + 1).min(value::MAX_FLOAT_PRECISION),
+                    )),
+                );
+            }
+        }
+    }
+
+    match address_at(state, position, bounds, options) {
+        Some((clicked_address, section)) => {
+            state.edit_section = section;
+            state.dragging = true;
+            let selection = match state.selection {
+                Some(selection) if state.modifiers.shift() => {
+                    extend_selection(content, state, selection, clicked_address, options)
+                }
+                _ => Selection::new(clicked_address),
+            };
+            (true, Some(Action::SelectionUpdate(Some(selection))))
+        }
+        None => (true, Some(Action::SelectionUpdate(None))),
+    }
+}
+
+fn address_at(
+    state: &State,
+    position: Point,
+    bounds: Rectangle,
+    options: &MemoryEditorOptions,
+) -> Option<(usize, Section)> {
+    let row_index = ((position.y - bounds.y) / state.dimensions.char_height).trunc() as usize;
+    if row_index >= state.dimensions.row_count {
+        return None;
     }
 
     let x_in_data = position.x - (bounds.x + state.dimensions.section_data_start);
     if x_in_data < 0.0 {
-        state.selected_address = None;
-        state.text.value_text.clear();
-        return (true, None);
+        return None;
+    }
+
+    let x_in_ascii = position.x - (bounds.x + state.dimensions.section_ascii_start);
+    let (byte_index, section) = if options.show_ascii && x_in_ascii >= 0.0 {
+        (
+            (x_in_ascii / state.dimensions.char_width) as usize,
+            Section::Ascii,
+        )
+    } else {
+        let row_bytes = state
+            .data
+            .len()
+            .saturating_sub(row_index * options.row_length)
+            .min(options.row_length);
+        (
+            calculate_byte_index(x_in_data, state, row_bytes),
+            Section::Hex,
+        )
+    };
+
+    if byte_index >= options.row_length
+        || row_index * options.row_length + byte_index >= state.data.len()
+    {
+        return None;
+    }
+
+    Some((
+        state.start_address + (row_index * options.row_length) + byte_index,
+        section,
+    ))
+}
+
+fn navigation_target(
+    state: &State,
+    key: keyboard::key::Named,
+    modifiers: keyboard::Modifiers,
+    options: &MemoryEditorOptions,
+    range: &Range<usize>,
+) -> Option<usize> {
+    if range.is_empty() {
+        return None;
+    }
+
+    let row_length = options.row_length;
+    let step = match state.edit_section {
+        Section::Hex => options.cell_size.bytes(),
+        Section::Ascii => 1,
+    };
+    let last = range.end - 1;
+    let current = state.cursor_address().unwrap_or(state.start_address);
+    let page = state.dimensions.row_count.max(1) * row_length;
+    let row_start = current.saturating_sub(
+        (current % row_length + row_length - state.start_address % row_length) % row_length,
+    );
+
+    let target = match key {
+        keyboard::key::Named::ArrowLeft => current.saturating_sub(step),
+        keyboard::key::Named::ArrowRight => current.saturating_add(step),
+        keyboard::key::Named::ArrowUp if current >= range.start + row_length => {
+            current - row_length
+        }
+        keyboard::key::Named::ArrowDown if current + row_length <= last => current + row_length,
+        keyboard::key::Named::ArrowUp | keyboard::key::Named::ArrowDown => current,
+        keyboard::key::Named::PageUp => current.saturating_sub(page),
+        keyboard::key::Named::PageDown => current.saturating_add(page),
+        keyboard::key::Named::Home if modifiers.control() => range.start,
+        keyboard::key::Named::End if modifiers.control() => last,
+        keyboard::key::Named::Home => row_start,
+        keyboard::key::Named::End => row_start.saturating_add(row_length - 1),
+        _ => return None,
+    };
+
+    Some(target.clamp(range.start, last))
+}
+
+fn next_writable_address<Context: MemoryEditorContext>(
+    content: &Content<Context>,
+    state: &State,
+    addr: usize,
+    row_length: usize,
+) -> Option<usize> {
+    let range = address_range(content);
+    let limit = state.dimensions.row_count.max(1) * row_length;
+    let internal = content.internal.borrow();
+
+    (addr + 1..range.end)
+        .take(limit)
+        .find(|&next| internal.context.can_write(next))
+}
+
+/// The addresses shown by the grid cell holding `addr`, words are counted
+/// from the start of their row. Words cut short by the end of the row or of
+/// the range are shown byte by byte.
+fn cell_range(
+    state: &State,
+    addr: usize,
+    options: &MemoryEditorOptions,
+    range: &Range<usize>,
+) -> Range<usize> {
+    let row_length = options.row_length;
+    let size = options.cell_size.bytes();
+    let row_offset =
+        (addr % row_length + row_length - state.start_address % row_length) % row_length;
+    let start = addr.saturating_sub(row_offset % size);
+    let end = (addr.saturating_sub(row_offset) + row_length).min(range.end);
+
+    if start + size > end {
+        addr..addr + 1
+    } else {
+        start..start + size
+    }
+}
+
+fn word_format(bytes: usize) -> PreviewDataFormat {
+    match bytes {
+        1 => PreviewDataFormat::U8,
+        2 => PreviewDataFormat::U16,
+        4 => PreviewDataFormat::U32,
+        _ => PreviewDataFormat::U64,
+    }
+}
+
+/// Extends the selection to `addr`, covering whole words in the grid.
+fn extend_selection<Context: MemoryEditorContext>(
+    content: &Content<Context>,
+    state: &State,
+    selection: Selection,
+    addr: usize,
+    options: &MemoryEditorOptions,
+) -> Selection {
+    if state.edit_section != Section::Hex {
+        return selection.extend(addr);
+    }
+
+    let range = address_range(content);
+    let anchor = cell_range(state, selection.anchor, options, &range);
+    let target = cell_range(state, addr, options, &range);
+
+    if target.start >= anchor.start {
+        Selection {
+            anchor: anchor.start,
+            cursor: target.end - 1,
+        }
+    } else {
+        Selection {
+            anchor: anchor.end - 1,
+            cursor: target.start,
+        }
+    }
+}
+
+fn start_address_showing(state: &State, addr: usize, row_length: usize) -> usize {
+    let visible = state.dimensions.row_count.max(1) * row_length;
+
+    if addr < state.start_address {
+        let rows = (state.start_address - addr).div_ceil(row_length);
+        state.start_address.saturating_sub(rows * row_length)
+    } else if addr >= state.start_address + visible {
+        let rows = (addr - state.start_address - visible) / row_length + 1;
+        state.start_address + rows * row_length
+    } else {
+        state.start_address
+    }
+}
+
+/// Maps an x offset in the data section to the index of the first byte of
+/// the cell under it, splitting the spacing between groups among their
+/// neighbouring cells. Words cut short by the `row_bytes` shown in the row
+/// are hit byte by byte.
+fn calculate_byte_index(x_in_data: f32, state: &State, row_bytes: usize) -> usize {
+    let cell_bytes = state.dimensions.cell_bytes;
+    let group_cells = (state.dimensions.group_char_len / cell_bytes).max(1);
+    let group_width =
+        state.dimensions.cell_width * group_cells as f32 + state.dimensions.group_spacing;
+    let group = ((x_in_data + state.dimensions.group_spacing / 2.0) / group_width) as usize;
+    let x_in_group = x_in_data - group as f32 * group_width;
+    let cell = ((x_in_group / state.dimensions.cell_width).max(0.0) as usize).min(group_cells - 1);
+    let start = (group * group_cells + cell) * cell_bytes;
+
+    if start + cell_bytes <= row_bytes {
+        return start;
+    }
+
+    let x_in_cell = x_in_group - cell as f32 * state.dimensions.cell_width;
+    let byte = (x_in_cell / state.dimensions.byte_width).max(0.0) as usize;
+    start + byte.min(row_bytes.saturating_sub(start + 1))
+}
+
+fn format_preview_value(data: &[u8], options: &MemoryEditorOptions) -> String {
+    let format = &options.preview_data_format;
+    if let Some(text) = value::format_float(
+        data,
+        *format,
+        options.endianness,
+        options.float_format,
+        options.float_precision,
+    ) {
+        return text;
+    }
+
+    match value::decode(data, *format, options.endianness) {
+        Some(Value::Unsigned(bits)) if *format == PreviewDataFormat::Bitfield => (0..4)
+            .rev()
+            .map(|nibble| format!("{:04b}", (bits >> (nibble * 4)) & 0xF))
+            .collect::<Vec<_>>()
+            .join(" "),
+        Some(value) => value.to_string(),
+        None => String::from("#Error#"),
+    }
+}
+
+fn background<Renderer>(renderer: &mut Renderer, style: &Style, bounds: Rectangle)
+where
+    Renderer: renderer::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            ..Default::default()
+        },
+        style.background,
+    );
+}
+
+fn separator<Renderer>(renderer: &mut Renderer, style: &Style, bounds: Rectangle, x: f32)
+where
+    Renderer: renderer::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: Rectangle {
+                x,
+                y: bounds.y + 2.0,
+                width: 1.0,
+                height: bounds.height - 4.0,
+            },
+            ..Default::default()
+        },
+        style.text_color,
+    );
+}
+
+fn row<Renderer>(
+    renderer: &mut Renderer,
+    style: &Style,
+    bounds: Rectangle,
+    state: &State,
+    addr: &usize,
+    row_data: &[Option<u8>],
+    options: &MemoryEditorOptions,
+) where
+    Renderer: renderer::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
+{
+    renderer.fill_text(
+        Text {
+            content: format!("{:06X}", addr),
+            bounds: Size::new(
+                state.dimensions.char_width * state.dimensions.address_char_len as f32,
+                bounds.height,
+            ),
+            ..state.text_defaults
+        },
+        Point::new(bounds.x, bounds.y),
+        style.inactive_text_color,
+        bounds,
+    );
+
+    let mut x_offset = bounds.x + state.dimensions.section_data_start;
+    let cell_bytes = options.cell_size.bytes();
+
+    for (cell_idx, cell) in row_data.chunks(cell_bytes).enumerate() {
+        let cell_addr = addr + cell_idx * cell_bytes;
+
+        if cell.len() == cell_bytes {
+            grid_cell(
+                renderer, style, bounds, state, x_offset, cell_addr, cell, options,
+            );
+        } else {
+            for (byte_idx, byte) in cell.iter().enumerate() {
+                grid_cell(
+                    renderer,
+                    style,
+                    bounds,
+                    state,
+                    x_offset + byte_idx as f32 * state.dimensions.byte_width,
+                    cell_addr + byte_idx,
+                    std::slice::from_ref(byte),
+                    options,
+                );
+            }
+        }
+
+        x_offset += state.dimensions.cell_width;
+        if ((cell_idx + 1) * cell_bytes).is_multiple_of(state.dimensions.group_char_len) {
+            x_offset += state.dimensions.group_spacing;
+        }
+    }
+
+    if options.show_ascii {
+        x_offset = bounds.x + state.dimensions.section_ascii_start;
+
+        let ascii_string: String = row_data
+            .iter()
+            .map(|&byte| match byte {
+                Some(byte) if (32..=126).contains(&byte) => byte as char,
+                Some(_) => '.',
+                None => '?',
+            })
+            .collect();
+
+        let string_width = ascii_string.len() as f32 * state.dimensions.char_width;
+
+        for byte_addr in (*addr..addr + row_data.len()).filter(|&a| state.is_match(a)) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x_offset + (byte_addr - addr) as f32 * state.dimensions.char_width,
+                        y: bounds.y,
+                        width: state.dimensions.char_width,
+                        height: bounds.height,
+                    },
+                    ..Default::default()
+                },
+                style.match_color,
+            );
+        }
+
+        renderer.fill_text(
+            Text {
+                content: ascii_string,
+                bounds: Size::new(string_width, bounds.height),
+                ..state.text_defaults
+            },
+            Point::new(x_offset, bounds.y),
+            style.inactive_text_color,
+            bounds,
+        );
+
+        for byte_addr in (*addr..addr + row_data.len()).filter(|&a| is_modified(state, a)) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: x_offset + (byte_addr - addr) as f32 * state.dimensions.char_width,
+                        y: bounds.y + bounds.height - 1.0,
+                        width: state.dimensions.char_width,
+                        height: 1.0,
+                    },
+                    ..Default::default()
+                },
+                style.modified_text_color,
+            );
+        }
+
+        if let Some(selection) = state.selection {
+            let range = selection.range();
+            for selected_addr in range.start.max(*addr)..range.end.min(addr + row_data.len()) {
+                let ascii_x = bounds.x
+                    + state.dimensions.section_ascii_start
+                    + ((selected_addr - addr) as f32 * state.dimensions.char_width);
+
+                cursor_quad(
+                    renderer,
+                    style,
+                    Rectangle {
+                        x: ascii_x,
+                        y: bounds.y,
+                        width: state.dimensions.char_width,
+                        height: bounds.height,
+                    },
+                    state.edit_section == Section::Ascii,
+                );
+            }
+        }
+    }
+}
+
+/// Draws the word made of `cell` at `x`, in the editor's radix.
+#[allow(clippy::too_many_arguments)]
+fn grid_cell<Renderer>(
+    renderer: &mut Renderer,
+    style: &Style,
+    bounds: Rectangle,
+    state: &State,
+    x: f32,
+    addr: usize,
+    cell: &[Option<u8>],
+    options: &MemoryEditorOptions,
+) where
+    Renderer: renderer::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
+{
+    let cell_range = addr..addr + cell.len();
+    let cell_bounds = Rectangle {
+        x,
+        y: bounds.y,
+        width: state.dimensions.char_width * options.cell_radix.digits(cell.len()) as f32,
+        height: bounds.height,
+    };
+    let is_selected = state
+        .selection
+        .is_some_and(|selection| cell_range.clone().any(|a| selection.contains(a)));
+    let is_cursor = state
+        .cursor_address()
+        .is_some_and(|cursor| cell_range.contains(&cursor));
+    let word = cell_value(cell, options);
+
+    if !is_selected && cell_range.clone().any(|a| state.is_match(a)) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: cell_bounds,
+                ..Default::default()
+            },
+            style.match_color,
+        );
+    }
+
+    if is_selected {
+        cursor_quad(
+            renderer,
+            style,
+            cell_bounds,
+            state.edit_section == Section::Hex,
+        );
+    }
+
+    renderer.fill_text(
+        Text {
+            content: cell_text(word, cell.len(), is_cursor, state, options),
+            bounds: cell_bounds.size(),
+            ..state.text_defaults
+        },
+        cell_bounds.position(),
+        if is_selected && state.edit_section == Section::Hex {
+            style.selected_text_color
+        } else if word.is_none() {
+            style.inactive_text_color
+        } else if cell_range.clone().any(|a| is_modified(state, a)) {
+            style.modified_text_color
+        } else {
+            style.text_color
+        },
+        bounds,
+    );
+}
+
+/// The word shown by a cell, in the editor's endianness.
+fn cell_value(cell: &[Option<u8>], options: &MemoryEditorOptions) -> Option<u64> {
+    let bytes: Vec<u8> = cell.iter().copied().collect::<Option<_>>()?;
+    match value::decode(&bytes, word_format(cell.len()), options.endianness)? {
+        Value::Unsigned(word) => u64::try_from(word).ok(),
+        _ => None,
+    }
+}
+
+/// The cell digits, with the ones typed so far replacing the leading ones.
+fn cell_text(
+    word: Option<u64>,
+    bytes: usize,
+    is_cursor: bool,
+    state: &State,
+    options: &MemoryEditorOptions,
+) -> String {
+    let current = match word {
+        Some(word) => options.cell_radix.format_word(word, bytes),
+        None => "?".repeat(options.cell_radix.digits(bytes)),
+    };
+
+    if !is_cursor || state.edit_digits.is_empty() {
+        return current;
+    }
+
+    let typed = if options.cell_radix == CellRadix::HexUpper {
+        state.edit_digits.to_uppercase()
+    } else {
+        state.edit_digits.to_lowercase()
+    };
+    typed + &current[state.edit_digits.len()..]
+}
+
+fn is_modified(state: &State, addr: usize) -> bool {
+    addr.checked_sub(state.start_address)
+        .and_then(|idx| state.modified.get(idx))
+        .is_some_and(|&modified| modified)
+}
+
+fn cursor_quad<Renderer>(renderer: &mut Renderer, style: &Style, bounds: Rectangle, active: bool)
+where
+    Renderer: renderer::Renderer,
+{
+    if active {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                ..Default::default()
+            },
+            style.selection_color,
+        );
+    } else {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    width: 1.0,
+                    color: style.selection_color,
+                    ..Border::default()
+                },
+                ..Default::default()
+            },
+            Color::TRANSPARENT,
+        );
+    }
+}
+
+fn options_panel<Renderer>(
+    tree: &widget::Tree,
+    renderer: &mut Renderer,
+    style: &Style,
+    layout: Layout<'_>,
+    options: &MemoryEditorOptions,
+) where
+    Renderer: renderer::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
+{
+    let state = tree.state.downcast_ref::<State>();
+
+    if !state.options_open {
+        return;
+    }
+
+    let panel_bounds = state.options_panel_bounds(&layout.bounds());
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: panel_bounds,
+            border: style.border,
+            shadow: style.shadow,
+        },
+        style.primary_color,
+    );
+
+    option_selector(
+        renderer,
+        state,
+        style,
+        panel_bounds,
+        "Row length",
+        options.row_length.to_string(),
+        &state.bounds.row_length,
+    );
+
+    label(
+        renderer,
+        state,
+        style,
+        "Cells",
+        state.bounds.row_length.next,
+    );
+    option_selector(
+        renderer,
+        state,
+        style,
+        panel_bounds,
+        "",
+        options.cell_radix.to_string(),
+        &state.bounds.cell_radix,
+    );
+
+    option_selector(
+        renderer,
+        state,
+        style,
+        panel_bounds,
+        "Preview data",
+        options.preview_data_format.to_string(),
+        &state.bounds.format,
+    );
+
+    option_selector(
+        renderer,
+        state,
+        style,
+        panel_bounds,
+        "Copy as",
+        options.copy_format.to_string(),
+        &state.bounds.copy_format,
+    );
+
+    option_selector(
+        renderer,
+        state,
+        style,
+        panel_bounds,
+        "Endianness",
+        options.endianness.to_string(),
+        &state.bounds.endianness,
+    );
+
+    label(
+        renderer,
+        state,
+        style,
+        "Group",
+        state.bounds.endianness.next,
+    );
+    option_selector(
+        renderer,
+        state,
+        style,
+        panel_bounds,
+        "",
+        options.cell_size.to_string(),
+        &state.bounds.cell_size,
+    );
+
+    option_selector(
+        renderer,
+        state,
+        style,
+        panel_bounds,
+        "Floats",
+        options.float_format.to_string(),
+        &state.bounds.float_format,
+    );
+
+    if options.float_format.has_precision() {
+        label(
+            renderer,
+            state,
+            style,
+            "Precision",
+            state.bounds.float_format.next,
+        );
+        option_selector(
+            renderer,
+            state,
+            style,
+            panel_bounds,
+            "",
+            options.float_precision.to_string(),
+            &state.bounds.float_precision,
+        );
+    }
+
+    let label_width = 120.0;
+
+    renderer.fill_text(
+        Text {
+            content: "Show ASCII".into(),
+            bounds: Size::new(label_width, state.dimensions.char_height),
+            ..state.text_defaults
+        },
+        Point::new(
+            panel_bounds.x + state.dimensions.char_width,
+            state.bounds.show_ascii_checkbox.y,
+        ),
+        style.text_color,
+        panel_bounds,
+    );
+
+    checkbox(
+        renderer,
+        style,
+        state.bounds.show_ascii_checkbox,
+        options.show_ascii,
+    );
+}
+
+fn checkbox<Renderer>(renderer: &mut Renderer, style: &Style, bounds: Rectangle, checked: bool)
+where
+    Renderer: renderer::Renderer,
+{
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border: Border {
+                width: 1.0,
+                color: style.text_color,
+                radius: (2.0).into(),
+            },
+            ..Default::default()
+        },
+        style.background,
+    );
+
+    if checked {
+        let padding = bounds.width * 0.2;
+        let inner_bounds = Rectangle {
+            x: bounds.x + padding,
+            y: bounds.y + padding,
+            width: bounds.width - (padding * 2.0),
+            height: bounds.height - (padding * 2.0),
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: inner_bounds,
+                border: Border {
+                    radius: (1.0).into(),
+                    ..Border::default()
+                },
+                ..Default::default()
+            },
+            style.text_color,
+        );
+    }
+}
+
+fn search_panel<Renderer>(
+    renderer: &mut Renderer,
+    state: &State,
+    style: &Style,
+    bounds: Rectangle,
+    options: &MemoryEditorOptions,
+) where
+    Renderer: renderer::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
+{
+    if !state.search_open {
+        return;
+    }
+
+    let panel_bounds = state.search_panel_bounds(&bounds);
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: panel_bounds,
+            border: style.border,
+            shadow: style.shadow,
+        },
+        style.primary_color,
+    );
+
+    renderer.fill_text(
+        Text {
+            content: "Find".into(),
+            bounds: Size::new(120.0, state.dimensions.char_height),
+            ..state.text_defaults
+        },
+        Point::new(
+            panel_bounds.x + state.dimensions.char_width,
+            state.bounds.search_input.y,
+        ),
+        style.text_color,
+        panel_bounds,
+    );
+
+    input_box(
+        renderer,
+        state,
+        style,
+        state.bounds.search_input,
+        &state.search_input,
+    );
+
+    let nav = &state.bounds.search_nav;
+    for (label, bounds) in [
+        ("<", nav.prev),
+        (">", nav.next),
+        ("All", state.bounds.find_all),
+    ] {
+        renderer.fill_text(
+            Text {
+                content: label.into(),
+                bounds: bounds.size(),
+                ..state.text_defaults
+            },
+            bounds.position(),
+            style.text_color,
+            bounds,
+        );
+    }
+
+    renderer.fill_text(
+        Text {
+            content: state.search_status.to_string(),
+            bounds: nav.text.size(),
+            ..state.text_defaults
+        },
+        nav.text.position(),
+        style.text_color,
+        nav.text,
+    );
+
+    option_selector(
+        renderer,
+        state,
+        style,
+        panel_bounds,
+        "Mode",
+        state.search_mode.to_string(),
+        &state.bounds.search_mode,
+    );
+
+    label(
+        renderer,
+        state,
+        style,
+        "With",
+        state.bounds.search_mode.next,
+    );
+    input_box(
+        renderer,
+        state,
+        style,
+        state.bounds.replace_input,
+        &state.replace_input,
+    );
+    for (label, bounds) in [
+        ("Replace", state.bounds.replace),
+        ("All", state.bounds.replace_all),
+    ] {
+        renderer.fill_text(
+            Text {
+                content: label.into(),
+                bounds: bounds.size(),
+                ..state.text_defaults
+            },
+            bounds.position(),
+            style.text_color,
+            bounds,
+        );
     }
 
-    let byte_index = calculate_byte_index(x_in_data, state);
-    if byte_index >= options.row_length {
-        state.selected_address = None;
-        state.text.value_text.clear();
-        return (true, None);
-    }
+    if state.search_mode == SearchMode::Text {
+        option_selector(
+            renderer,
+            state,
+            style,
+            panel_bounds,
+            "Encoding",
+            state.text_encoding.to_string(),
+            &state.bounds.text_encoding,
+        );
 
-    let clicked_address = state.start_address + (row_index * options.row_length) + byte_index;
-    state.selected_address = Some(clicked_address);
-    state.text.value_text = format!("{:06X} =", clicked_address);
-    state.text.value_len = state.text.value_text.len() as f32 * state.dimensions.char_width;
+        label(
+            renderer,
+            state,
+            style,
+            "Match case",
+            state.bounds.text_encoding.next,
+        );
+        checkbox(
+            renderer,
+            style,
+            state.bounds.match_case_checkbox,
+            state.match_case,
+        );
+    }
 
-    (true, None)
+    if state.search_mode == SearchMode::Value {
+        value_options(renderer, state, style, panel_bounds, options);
+    }
 }
 
-fn calculate_byte_index(x_in_data: f32, state: &State) -> usize {
-    let total_x = x_in_data + state.dimensions.group_spacing / 2.0;
-    let byte_slot_width = state.dimensions.byte_width
-        + (state.dimensions.group_spacing / state.dimensions.group_char_len as f32);
-
-    (total_x / byte_slot_width) as usize
-}
+/// Draws the format, alignment, endianness and epsilon controls shared by the
+/// search and scan panels.
+fn value_options<Renderer>(
+    renderer: &mut Renderer,
+    state: &State,
+    style: &Style,
+    panel_bounds: Rectangle,
+    options: &MemoryEditorOptions,
+) where
+    Renderer: renderer::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
+{
+    option_selector(
+        renderer,
+        state,
+        style,
+        panel_bounds,
+        "Format",
+        state.value_format.to_string(),
+        &state.bounds.value_format,
+    );
+    label(
+        renderer,
+        state,
+        style,
+        "Aligned",
+        state.bounds.value_format.next,
+    );
+    checkbox(
+        renderer,
+        style,
+        state.bounds.aligned_checkbox,
+        state.aligned,
+    );
 
-fn format_preview_value(data: &[u8], format: &PreviewDataFormat) -> String {
-    if data.is_empty() {
-        return String::from("#Error#");
-    }
+    option_selector(
+        renderer,
+        state,
+        style,
+        panel_bounds,
+        "Endianness",
+        options.endianness.to_string(),
+        &state.bounds.value_endianness,
+    );
 
-    match format {
-        PreviewDataFormat::U8 => bytemuck::from_bytes::<u8>(data).to_string(),
-        PreviewDataFormat::U16 => bytemuck::from_bytes::<u16>(data).to_string(),
-        PreviewDataFormat::U32 => bytemuck::from_bytes::<u32>(data).to_string(),
-        PreviewDataFormat::U64 => bytemuck::from_bytes::<u64>(data).to_string(),
-        PreviewDataFormat::I8 => bytemuck::from_bytes::<i8>(data).to_string(),
-        PreviewDataFormat::I16 => bytemuck::from_bytes::<i16>(data).to_string(),
-        PreviewDataFormat::I32 => bytemuck::from_bytes::<i32>(data).to_string(),
-        PreviewDataFormat::I64 => bytemuck::from_bytes::<i64>(data).to_string(),
-        PreviewDataFormat::F32 => format!("{:.3}", bytemuck::from_bytes::<f32>(data)), // TODO: fix
-        PreviewDataFormat::F64 => format!("{:.3}", bytemuck::from_bytes::<f64>(data)), // TODO: fix
+    if state.value_format.is_float() {
+        label(
+            renderer,
+            state,
+            style,
+            "Epsilon",
+            state.bounds.value_endianness.next,
+        );
+        input_box(
+            renderer,
+            state,
+            style,
+            state.bounds.epsilon_input,
+            &state.epsilon_input,
+        );
     }
 }
 
-fn background<Renderer>(renderer: &mut Renderer, style: &Style, bounds: Rectangle)
-where
-    Renderer: renderer::Renderer,
+fn scan_panel<Renderer>(
+    renderer: &mut Renderer,
+    state: &State,
+    style: &Style,
+    bounds: Rectangle,
+    options: &MemoryEditorOptions,
+) where
+    Renderer: renderer::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
 {
+    if !state.scan_open {
+        return;
+    }
+
+    let panel_bounds = state.scan_panel_bounds(&bounds);
+
     renderer.fill_quad(
         renderer::Quad {
-            bounds,
-            ..Default::default()
+            bounds: panel_bounds,
+            border: style.border,
+            shadow: style.shadow,
         },
-        style.background,
+        style.primary_color,
     );
-}
 
-fn separator<Renderer>(renderer: &mut Renderer, style: &Style, bounds: Rectangle, x: f32)
-where
-    Renderer: renderer::Renderer,
-{
-    renderer.fill_quad(
-        renderer::Quad {
-            bounds: Rectangle {
-                x,
-                y: bounds.y + 2.0,
-                width: 1.0,
-                height: bounds.height - 4.0,
+    renderer.fill_text(
+        Text {
+            content: "Value".into(),
+            bounds: Size::new(120.0, state.dimensions.char_height),
+            ..state.text_defaults
+        },
+        Point::new(
+            panel_bounds.x + state.dimensions.char_width,
+            state.bounds.scan_input.y,
+        ),
+        style.text_color,
+        panel_bounds,
+    );
+
+    input_box(
+        renderer,
+        state,
+        style,
+        state.bounds.scan_input,
+        &state.scan_input,
+    );
+
+    for (label, bounds) in [
+        ("First", state.bounds.scan_first),
+        ("Next", state.bounds.scan_next),
+        ("Reset", state.bounds.scan_reset),
+    ] {
+        renderer.fill_text(
+            Text {
+                content: label.into(),
+                bounds: bounds.size(),
+                ..state.text_defaults
             },
-            ..Default::default()
+            bounds.position(),
+            style.text_color,
+            bounds,
+        );
+    }
+
+    renderer.fill_text(
+        Text {
+            content: state.scan_status.to_string(),
+            bounds: state.bounds.scan_status.size(),
+            ..state.text_defaults
         },
+        state.bounds.scan_status.position(),
         style.text_color,
+        state.bounds.scan_status,
+    );
+
+    option_selector(
+        renderer,
+        state,
+        style,
+        panel_bounds,
+        "Compare",
+        state.scan_filter.to_string(),
+        &state.bounds.scan_filter,
     );
+
+    value_options(renderer, state, style, panel_bounds, options);
 }
 
-fn row<Renderer>(
+fn inspector_panel<Renderer, Context: MemoryEditorContext>(
+    content: &Content<Context>,
     renderer: &mut Renderer,
+    state: &State,
     style: &Style,
     bounds: Rectangle,
-    state: &State,
-    addr: &usize,
-    row_data: &[u8],
     options: &MemoryEditorOptions,
 ) where
     Renderer: renderer::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
 {
-    renderer.fill_text(
-        Text {
-            content: format!("{:06X}", addr),
-            bounds: Size::new(
-                state.dimensions.char_width * state.dimensions.address_char_len as f32,
-                bounds.height,
-            ),
-            ..state.text_defaults
+    if !state.inspector_open {
+        return;
+    }
+
+    let panel_bounds = state.inspector_panel_bounds(&bounds);
+
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: panel_bounds,
+            border: style.border,
+            shadow: style.shadow,
         },
-        Point::new(bounds.x, bounds.y),
-        style.inactive_text_color,
-        bounds,
+        style.primary_color,
     );
 
-    let mut x_offset = bounds.x + state.dimensions.section_data_start;
+    if state.inspector_data.is_empty() {
+        return;
+    }
+
+    let columns = inspector::entries(
+        &state.inspector_data,
+        inspector::pointer_size(address_range(content).end),
+        options,
+    );
+    let column_width = (panel_bounds.width - state.dimensions.char_width) / columns.len() as f32;
+    let label_width = state.dimensions.char_width * 8.0;
+
+    for (index, column) in columns.iter().enumerate() {
+        let x = panel_bounds.x + state.dimensions.char_width + column_width * index as f32;
+        let mut y = panel_bounds.y + state.dimensions.char_height * 0.5;
+
+        for (name, value) in column {
+            let entry_bounds = Rectangle {
+                x,
+                y,
+                width: column_width - state.dimensions.char_width,
+                height: state.dimensions.char_height,
+            };
+
+            renderer.fill_text(
+                Text {
+                    content: name.clone(),
+                    bounds: Size::new(label_width, entry_bounds.height),
+                    ..state.text_defaults
+                },
+                entry_bounds.position(),
+                style.inactive_text_color,
+                entry_bounds,
+            );
+
+            renderer.fill_text(
+                Text {
+                    content: value.clone(),
+                    bounds: Size::new(entry_bounds.width - label_width, entry_bounds.height),
+                    ..state.text_defaults
+                },
+                Point::new(x + label_width, y),
+                style.text_color,
+                entry_bounds,
+            );
+
+            y += state.dimensions.char_height;
+        }
+    }
+}
+
+/// Lists the scan candidates with their current value, highlighting the ones
+/// that changed since the last scan.
+fn scan_list<Renderer>(
+    renderer: &mut Renderer,
+    state: &State,
+    style: &Style,
+    options: &MemoryEditorOptions,
+) where
+    Renderer: renderer::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
+{
+    let Some(scanner) = state.scanner.as_ref().filter(|_| state.scan_visible()) else {
+        return;
+    };
 
-    for (byte_idx, byte) in row_data.iter().enumerate() {
-        let byte_addr = addr + byte_idx;
-        let is_selected = state.selected_address == Some(byte_addr);
+    let panel_bounds = state.bounds.scan_list;
 
-        if is_selected {
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: panel_bounds,
+            border: style.border,
+            shadow: style.shadow,
+        },
+        style.primary_color,
+    );
+
+    let mut y = panel_bounds.y;
+    for (line, candidate) in scanner
+        .candidates(state.scan_scroll)
+        .take(state.dimensions.row_count)
+        .enumerate()
+    {
+        let line_bounds = Rectangle {
+            y,
+            height: state.dimensions.char_height,
+            ..panel_bounds
+        };
+        let current = state
+            .scan_values
+            .get(line)
+            .copied()
+            .flatten()
+            .unwrap_or(candidate.value);
+        let is_current = state.cursor_address() == Some(candidate.address);
+
+        if is_current {
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: Rectangle {
-                        x: x_offset,
-                        y: bounds.y,
-                        width: state.dimensions.char_width * 2.0,
-                        height: bounds.height,
-                    },
+                    bounds: line_bounds,
                     ..Default::default()
                 },
                 style.selection_color,
@@ -763,177 +3259,182 @@ fn row<Renderer>(
 
         renderer.fill_text(
             Text {
-                content: format!("{:02x}", byte),
-                bounds: Size::new(state.dimensions.char_width * 2.0, bounds.height),
+                content: format!(
+                    "{:06X} {}",
+                    candidate.address,
+                    scanner.display(current, options)
+                ),
+                bounds: line_bounds.size(),
                 ..state.text_defaults
             },
-            Point::new(x_offset, bounds.y),
-            if is_selected {
+            Point::new(line_bounds.x + state.dimensions.char_width, y),
+            if is_current {
                 style.selected_text_color
+            } else if current != candidate.value {
+                style.modified_text_color
             } else {
                 style.text_color
             },
-            bounds,
+            line_bounds,
         );
 
-        x_offset += state.dimensions.byte_width;
-        if (byte_idx + 1) % state.dimensions.group_char_len == 0 {
-            x_offset += state.dimensions.group_spacing;
-        }
+        y += state.dimensions.char_height;
     }
+}
 
-    if options.show_ascii {
-        x_offset = bounds.x + state.dimensions.section_ascii_start;
+fn results_panel<Renderer>(renderer: &mut Renderer, state: &State, style: &Style)
+where
+    Renderer: renderer::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
+{
+    if !state.results_visible() {
+        return;
+    }
 
-        let ascii_string: String = row_data
-            .iter()
-            .map(|&byte| {
-                if (32..=126).contains(&byte) {
-                    byte as char
-                } else {
-                    '.'
-                }
-            })
-            .collect();
+    let panel_bounds = state.bounds.results;
 
-        let string_width = ascii_string.len() as f32 * state.dimensions.char_width;
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds: panel_bounds,
+            border: style.border,
+            shadow: style.shadow,
+        },
+        style.primary_color,
+    );
+
+    let mut y = panel_bounds.y;
+    for &addr in state
+        .search_results
+        .iter()
+        .skip(state.results_scroll)
+        .take(state.dimensions.row_count)
+    {
+        let line_bounds = Rectangle {
+            y,
+            height: state.dimensions.char_height,
+            ..panel_bounds
+        };
+        let is_current = state.cursor_address() == Some(addr);
+
+        if is_current {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: line_bounds,
+                    ..Default::default()
+                },
+                style.selection_color,
+            );
+        }
 
         renderer.fill_text(
             Text {
-                content: ascii_string,
-                bounds: Size::new(string_width, bounds.height),
+                content: format!("{:06X}", addr),
+                bounds: line_bounds.size(),
                 ..state.text_defaults
             },
-            Point::new(x_offset, bounds.y),
-            style.inactive_text_color,
-            bounds,
+            Point::new(line_bounds.x + state.dimensions.char_width, y),
+            if is_current {
+                style.selected_text_color
+            } else {
+                style.text_color
+            },
+            line_bounds,
         );
 
-        if let Some(selected_addr) = state.selected_address {
-            if selected_addr >= *addr && selected_addr < addr + options.row_length {
-                let ascii_x = bounds.x
-                    + state.dimensions.section_ascii_start
-                    + ((selected_addr - addr) as f32 * state.dimensions.char_width);
-
-                renderer.fill_quad(
-                    renderer::Quad {
-                        bounds: Rectangle {
-                            x: ascii_x,
-                            y: bounds.y,
-                            width: state.dimensions.char_width,
-                            height: bounds.height,
-                        },
-                        ..Default::default()
-                    },
-                    style.selection_color,
-                );
-            }
-        }
+        y += state.dimensions.char_height;
     }
 }
 
-fn options_panel<Renderer>(
-    tree: &widget::Tree,
+/// Draws a label after the given selector button.
+fn label<Renderer>(
     renderer: &mut Renderer,
-    style: &Style,
-    layout: Layout<'_>,
-    options: &MemoryEditorOptions,
+    state: &State,
+    style: &Style,
+    text: &str,
+    after: Rectangle,
 ) where
     Renderer: renderer::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
 {
-    let state = tree.state.downcast_ref::<State>();
-
-    if !state.options_open {
-        return;
-    }
-
-    let bounds = layout.bounds();
-
-    let panel_bounds = Rectangle {
-        y: bounds.y + bounds.height
-            - state.dimensions.char_height * 1.5
-            - state.dimensions.char_height * 4.0,
-        height: state.dimensions.char_height * 4.0,
-        ..bounds
+    let bounds = Rectangle {
+        x: after.x + state.dimensions.char_width * 3.0,
+        width: state.dimensions.char_width * text.len() as f32,
+        ..after
     };
 
-    renderer.fill_quad(
-        renderer::Quad {
-            bounds: panel_bounds,
-            border: style.border,
-            shadow: style.shadow,
-        },
-        style.primary_color,
-    );
-
-    let label_width = 120.0;
-    let offset_y = panel_bounds.y + state.dimensions.char_height * 0.5;
-
     renderer.fill_text(
         Text {
-            content: "Row length".into(),
-            bounds: Size::new(label_width, state.dimensions.char_height),
+            content: text.into(),
+            bounds: bounds.size(),
             ..state.text_defaults
         },
-        Point::new(panel_bounds.x + state.dimensions.char_width, offset_y),
+        bounds.position(),
         style.text_color,
-        panel_bounds,
+        bounds,
     );
+}
 
-    renderer.fill_text(
-        Text {
-            content: "<".into(),
-            bounds: Size::new(state.dimensions.char_width, state.dimensions.char_height),
-            ..state.text_defaults
+fn input_box<Renderer>(
+    renderer: &mut Renderer,
+    state: &State,
+    style: &Style,
+    bounds: Rectangle,
+    input: &InputState,
+) where
+    Renderer: renderer::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
+{
+    renderer.fill_quad(
+        Quad {
+            bounds,
+            border: Border {
+                width: 1.0,
+                color: if input.focused {
+                    style.text_color
+                } else {
+                    style.border.color
+                },
+                ..style.border
+            },
+            ..Default::default()
         },
-        Point::new(
-            state.bounds.prev_row_length.x,
-            state.bounds.prev_row_length.y,
-        ),
-        style.text_color,
-        state.bounds.prev_row_length,
+        style.background,
     );
 
-    renderer.fill_text(
-        Text {
-            content: format!("{}", options.row_length),
-            bounds: Size::new(
-                state.dimensions.char_width * 3.0,
-                state.dimensions.char_height,
-            ),
-            ..state.text_defaults
-        },
-        Point::new(
-            state.bounds.text_row_length.x,
-            state.bounds.text_row_length.y,
-        ),
-        style.text_color,
-        state.bounds.text_row_length,
-    );
+    let visible = ((bounds.width / state.dimensions.char_width) as usize).saturating_sub(1);
+    let skip = input.value.chars().count().saturating_sub(visible);
 
     renderer.fill_text(
         Text {
-            content: ">".into(),
-            bounds: Size::new(state.dimensions.char_width, state.dimensions.char_height),
+            content: input.value.chars().skip(skip).collect(),
+            bounds: Size::new(bounds.width - state.dimensions.char_width, bounds.height),
             ..state.text_defaults
         },
-        Point::new(
-            state.bounds.next_row_length.x,
-            state.bounds.next_row_length.y,
-        ),
+        Point::new(bounds.x + state.dimensions.char_width / 2.0, bounds.y),
         style.text_color,
-        state.bounds.next_row_length,
+        bounds,
     );
+}
+
+fn option_selector<Renderer>(
+    renderer: &mut Renderer,
+    state: &State,
+    style: &Style,
+    panel_bounds: Rectangle,
+    label: &str,
+    value: String,
+    selector: &SelectorBounds,
+) where
+    Renderer: renderer::Renderer + iced_core::text::Renderer<Font = iced_core::Font>,
+{
+    let label_width = 120.0;
 
     renderer.fill_text(
         Text {
-            content: "Preview data".into(),
+            content: label.into(),
             bounds: Size::new(label_width, state.dimensions.char_height),
             ..state.text_defaults
         },
         Point::new(
             panel_bounds.x + state.dimensions.char_width,
-            offset_y + state.dimensions.char_height,
+            selector.text.y,
         ),
         style.text_color,
         panel_bounds,
@@ -945,23 +3446,20 @@ fn options_panel<Renderer>(
             bounds: Size::new(state.dimensions.char_width, state.dimensions.char_height),
             ..state.text_defaults
         },
-        Point::new(state.bounds.prev_format.x, state.bounds.prev_format.y),
+        Point::new(selector.prev.x, selector.prev.y),
         style.text_color,
-        state.bounds.prev_format,
+        selector.prev,
     );
 
     renderer.fill_text(
         Text {
-            content: format!("{}", options.preview_data_format),
-            bounds: Size::new(
-                state.dimensions.char_width * 3.0,
-                state.dimensions.char_height,
-            ),
+            content: value,
+            bounds: Size::new(selector.text.width, selector.text.height),
             ..state.text_defaults
         },
-        Point::new(state.bounds.text_format.x, state.bounds.text_format.y),
+        Point::new(selector.text.x, selector.text.y),
         style.text_color,
-        state.bounds.text_format,
+        selector.text,
     );
 
     renderer.fill_text(
@@ -970,62 +3468,10 @@ fn options_panel<Renderer>(
             bounds: Size::new(state.dimensions.char_width, state.dimensions.char_height),
             ..state.text_defaults
         },
-        Point::new(state.bounds.next_format.x, state.bounds.next_format.y),
+        Point::new(selector.next.x, selector.next.y),
         style.text_color,
-        state.bounds.next_format,
-    );
-
-    renderer.fill_text(
-        Text {
-            content: "Show ASCII".into(),
-            bounds: Size::new(label_width, state.dimensions.char_height),
-            ..state.text_defaults
-        },
-        Point::new(
-            panel_bounds.x + state.dimensions.char_width,
-            state.bounds.show_ascii_checkbox.y,
-        ),
-        style.text_color,
-        panel_bounds,
-    );
-
-    let checkbox_size = state.dimensions.char_height * 0.8;
-    let checkbox_bounds = state.bounds.show_ascii_checkbox;
-
-    renderer.fill_quad(
-        renderer::Quad {
-            bounds: checkbox_bounds,
-            border: Border {
-                width: 1.0,
-                color: style.text_color,
-                radius: (2.0).into(),
-            },
-            ..Default::default()
-        },
-        style.background,
+        selector.next,
     );
-
-    if options.show_ascii {
-        let padding = checkbox_size * 0.2;
-        let inner_bounds = Rectangle {
-            x: checkbox_bounds.x + padding,
-            y: checkbox_bounds.y + padding,
-            width: checkbox_bounds.width - (padding * 2.0),
-            height: checkbox_bounds.height - (padding * 2.0),
-        };
-
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds: inner_bounds,
-                border: Border {
-                    radius: (1.0).into(),
-                    ..Border::default()
-                },
-                ..Default::default()
-            },
-            style.text_color,
-        );
-    }
 }
 
 fn bottom_panel<Renderer, Context: MemoryEditorContext>(
@@ -1078,6 +3524,48 @@ fn bottom_panel<Renderer, Context: MemoryEditorContext>(
         panel_options_bounds,
     );
 
+    renderer.fill_text(
+        Text {
+            content: "Search".to_string(),
+            bounds: Size::new(state.bounds.search_toggle.width, panel_bounds.height),
+            ..state.text_defaults
+        },
+        Point::new(
+            state.bounds.search_toggle.x,
+            panel_bounds.y + state.dimensions.char_width / 2.0,
+        ),
+        style.text_color,
+        state.bounds.search_toggle,
+    );
+
+    renderer.fill_text(
+        Text {
+            content: "Scan".to_string(),
+            bounds: Size::new(state.bounds.scan_toggle.width, panel_bounds.height),
+            ..state.text_defaults
+        },
+        Point::new(
+            state.bounds.scan_toggle.x,
+            panel_bounds.y + state.dimensions.char_width / 2.0,
+        ),
+        style.text_color,
+        state.bounds.scan_toggle,
+    );
+
+    renderer.fill_text(
+        Text {
+            content: "Inspect".to_string(),
+            bounds: Size::new(state.bounds.inspector_toggle.width, panel_bounds.height),
+            ..state.text_defaults
+        },
+        Point::new(
+            state.bounds.inspector_toggle.x,
+            panel_bounds.y + state.dimensions.char_width / 2.0,
+        ),
+        style.text_color,
+        state.bounds.inspector_toggle,
+    );
+
     let jumpto_bounds = Rectangle {
         x: state.bounds.addr_input.x - state.text.jumpto_len - state.dimensions.char_width,
         y: panel_bounds.y,
@@ -1135,7 +3623,7 @@ fn bottom_panel<Renderer, Context: MemoryEditorContext>(
         panel_input_bounds,
     );
 
-    if let Some(selected_addr) = state.selected_address {
+    if let Some(selected_addr) = state.cursor_address() {
         if content.internal.borrow().context.can_write(selected_addr) {
             let value_bounds = Rectangle {
                 x: state.bounds.byte_input.x - state.text.value_len - state.dimensions.char_width,
@@ -1177,9 +3665,18 @@ fn bottom_panel<Renderer, Context: MemoryEditorContext>(
                 style.primary_color,
             );
 
+            let visible = ((byte_input_bounds.width / state.dimensions.char_width) as usize)
+                .saturating_sub(1);
+            let skip = state
+                .byte_input
+                .value
+                .chars()
+                .count()
+                .saturating_sub(visible);
+
             renderer.fill_text(
                 Text {
-                    content: state.byte_input.value.clone(),
+                    content: state.byte_input.value.chars().skip(skip).collect(),
                     bounds: Size::new(
                         byte_input_bounds.width - state.dimensions.char_width,
                         byte_input_bounds.height,
@@ -1195,28 +3692,30 @@ fn bottom_panel<Renderer, Context: MemoryEditorContext>(
             );
         }
 
-        if selected_addr >= state.start_address
+        if state.edit_status == Status::Idle
+            && selected_addr >= state.start_address
             && selected_addr < state.start_address + state.dimensions.row_count * options.row_length
         {
-            let required_bytes = match options.preview_data_format {
-                PreviewDataFormat::U8 | PreviewDataFormat::I8 => 1,
-                PreviewDataFormat::U16 | PreviewDataFormat::I16 => 2,
-                PreviewDataFormat::U32 | PreviewDataFormat::I32 | PreviewDataFormat::F32 => 4,
-                PreviewDataFormat::U64 | PreviewDataFormat::I64 | PreviewDataFormat::F64 => 8,
-            };
+            let required_bytes = options.preview_data_format.size();
+            let offset = selected_addr - state.start_address;
 
-            let mut preview_data = [0u8; 8];
-            if let Some(data_slice) = state.data.get(
-                selected_addr - state.start_address
-                    ..selected_addr - state.start_address + required_bytes,
-            ) {
-                preview_data[..required_bytes].copy_from_slice(data_slice);
-            }
+            let preview_data: Option<Vec<u8>> = if options.preview_data_format.is_variable_length()
+            {
+                state
+                    .data
+                    .get(offset..(offset + required_bytes).min(state.data.len()))
+                    .map(|data_slice| data_slice.iter().map_while(|byte| *byte).collect())
+            } else {
+                state
+                    .data
+                    .get(offset..offset + required_bytes)
+                    .and_then(|data_slice| data_slice.iter().copied().collect())
+            };
 
-            let value_text = format_preview_value(
-                &preview_data[..required_bytes],
-                &options.preview_data_format,
-            );
+            let value_text = match preview_data {
+                Some(preview_data) => format_preview_value(&preview_data, options),
+                None => "??".to_string(),
+            };
 
             let value_width = value_text.len() as f32 * state.dimensions.char_width;
             let value_bound = Rectangle {
@@ -1241,25 +3740,122 @@ fn bottom_panel<Renderer, Context: MemoryEditorContext>(
             );
         }
     }
+
+    if state.edit_status != Status::Idle {
+        let status_text = state.edit_status.to_string();
+        let status_width = status_text.len() as f32 * state.dimensions.char_width;
+        let status_bounds = Rectangle {
+            x: panel_bounds.x + panel_bounds.width - status_width - state.dimensions.char_width,
+            y: panel_bounds.y,
+            width: status_width,
+            height: panel_bounds.height,
+        };
+
+        renderer.fill_text(
+            Text {
+                content: status_text,
+                bounds: Size::new(status_width, panel_bounds.height),
+                ..state.text_defaults
+            },
+            Point::new(
+                status_bounds.x,
+                panel_bounds.y + state.dimensions.char_width / 2.0,
+            ),
+            style.text_color,
+            status_bounds,
+        );
+    }
+}
+
+struct StateOperation<F> {
+    target: Id,
+    apply: F,
+}
+
+impl<T, F> Operation<T> for StateOperation<F>
+where
+    F: FnMut(&mut State) + Send,
+{
+    #[cfg(feature = "iced")]
+    fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+        operate(self);
+    }
+
+    #[cfg(feature = "libcosmic")]
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    #[cfg(feature = "iced")]
+    fn custom(&mut self, id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Any) {
+        self.apply_to(id, state);
+    }
+
+    #[cfg(feature = "libcosmic")]
+    fn custom(&mut self, state: &mut dyn Any, id: Option<&Id>) {
+        self.apply_to(id, state);
+    }
+}
+
+impl<F> StateOperation<F>
+where
+    F: FnMut(&mut State),
+{
+    fn apply_to(&mut self, id: Option<&Id>, state: &mut dyn Any) {
+        if id != Some(&self.target) {
+            return;
+        }
+        if let Some(state) = state.downcast_mut::<State>() {
+            (self.apply)(state);
+        }
+    }
+}
+
+pub fn scroll_to<T>(id: Id, address: usize) -> impl Operation<T> {
+    StateOperation {
+        target: id,
+        apply: move |state: &mut State| {
+            state.pending_scroll = Some(address);
+        },
+    }
+}
+
+pub fn select<T>(id: Id, range: Range<usize>) -> impl Operation<T> {
+    StateOperation {
+        target: id,
+        apply: move |state: &mut State| {
+            state.pending_selection = Some(range.clone());
+        },
+    }
+}
+
+pub fn focus<T>(id: Id) -> impl Operation<T> {
+    focusable::focus(id)
 }
 
 #[cfg(feature = "iced")]
-impl<'a, Context, Theme, Message, Renderer> From<MemoryEditor<'a, Context, Theme>>
+impl<'a, Context, Theme, Message, Renderer> From<MemoryEditor<'a, Context, Message, Theme>>
     for Element<'a, Message, Theme, Renderer>
 where
     Renderer: renderer::Renderer + iced_core::text::Renderer<Font = iced_core::Font> + 'a,
     Theme: Catalog + iced_core::widget::text::Catalog + 'a,
     Context: context::MemoryEditorContext + 'a,
+    Message: 'a,
 {
-    fn from(memory_editor: MemoryEditor<'a, Context, Theme>) -> Self {
+    fn from(memory_editor: MemoryEditor<'a, Context, Message, Theme>) -> Self {
         Self::new(memory_editor)
     }
 }
 
 #[cfg(feature = "iced")]
-pub fn memory_editor<'a, Context, Theme>(
+pub fn memory_editor<'a, Context, Message, Theme>(
     content: &'a Content<Context>,
-) -> MemoryEditor<'a, Context, Theme>
+) -> MemoryEditor<'a, Context, Message, Theme>
 where
     Context: context::MemoryEditorContext + 'a,
     Theme: Catalog + iced_core::widget::text::Catalog + 'a,
@@ -1268,19 +3864,195 @@ where
 }
 
 #[cfg(feature = "libcosmic")]
-impl<'a, Context, Message> From<MemoryEditor<'a, Context>> for cosmic::Element<'a, Message>
+impl<'a, Context, Message> From<MemoryEditor<'a, Context, Message>> for cosmic::Element<'a, Message>
 where
     Context: context::MemoryEditorContext + 'a,
+    Message: 'a,
 {
-    fn from(memory_editor: MemoryEditor<'a, Context>) -> Self {
+    fn from(memory_editor: MemoryEditor<'a, Context, Message>) -> Self {
         Self::new(memory_editor)
     }
 }
 
 #[cfg(feature = "libcosmic")]
-pub fn memory_editor<'a, Context>(content: &'a Content<Context>) -> MemoryEditor<'a, Context>
+pub fn memory_editor<'a, Context, Message>(
+    content: &'a Content<Context>,
+) -> MemoryEditor<'a, Context, Message>
 where
     Context: context::MemoryEditorContext + 'a,
 {
     MemoryEditor::new(content)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Memory {
+        data: Vec<u8>,
+        options: MemoryEditorOptions,
+    }
+
+    impl MemoryEditorContext for Memory {
+        fn data(&self, range: Range<usize>) -> Vec<Option<u8>> {
+            let end = range.end.min(self.data.len());
+            self.data[range.start.min(end)..end]
+                .iter()
+                .copied()
+                .map(Some)
+                .collect()
+        }
+
+        fn address_range(&self) -> Range<usize> {
+            0..self.data.len()
+        }
+
+        fn options(&self) -> MemoryEditorOptions {
+            self.options.clone()
+        }
+
+        fn write_options(&mut self, options: MemoryEditorOptions) {
+            self.options = options;
+        }
+
+        fn can_write(&self, address: usize) -> bool {
+            address < self.data.len()
+        }
+
+        fn write(&mut self, address: usize, value: u8) {
+            self.data[address] = value;
+        }
+
+        fn refresh_data(&mut self) -> bool {
+            false
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Message {
+        Selected(Option<Selection>),
+        Scrolled(usize),
+    }
+
+    fn content() -> Content<Memory> {
+        Content::new(Memory {
+            data: (0..=255).collect(),
+            options: MemoryEditorOptions::default(),
+        })
+    }
+
+    fn run(operation: &mut dyn Operation<()>, id: &Id, state: &mut State) {
+        #[cfg(feature = "iced")]
+        operation.custom(Some(id), Rectangle::default(), state);
+        #[cfg(feature = "libcosmic")]
+        operation.custom(state, Some(id));
+    }
+
+    fn apply(
+        content: &Content<Memory>,
+        state: &mut State,
+        bindings: &Bindings<'_, Message>,
+    ) -> Vec<Message> {
+        let mut messages = Vec::new();
+        let mut shell = iced_core::Shell::new(&mut messages);
+        apply_operations(content, state, &options(content), &mut shell, bindings);
+        messages
+    }
+
+    #[test]
+    fn operations() {
+        let content = content();
+        let id = Id::new("memory");
+        let mut state = State::default();
+        state.dimensions.row_count = 4;
+        let bindings = Bindings {
+            on_select: Some(Box::new(Message::Selected)),
+            on_scroll: Some(Box::new(Message::Scrolled)),
+            ..Bindings::default()
+        };
+
+        run(&mut scroll_to(id.clone(), 0x1234), &id, &mut state);
+        run(&mut select(id.clone(), 0xF8..0x108), &id, &mut state);
+        run(&mut scroll_to(Id::new("other"), 0x40), &id, &mut state);
+        assert_eq!(state.start_address, 0);
+
+        let messages = apply(&content, &mut state, &bindings);
+
+        // 256 bytes shown 4 rows of 16 at a time
+        let selection = Selection::from_range(0xF8..0x100);
+        assert_eq!(state.start_address, 0xC0);
+        assert_eq!(state.data.first(), Some(&Some(0xC0)));
+        assert_eq!(state.selection, selection);
+        assert_eq!(
+            messages,
+            vec![Message::Scrolled(0xC0), Message::Selected(selection)]
+        );
+        assert!(apply(&content, &mut state, &bindings).is_empty());
+    }
+
+    #[test]
+    fn typed_values() {
+        let content = content();
+        let options = MemoryEditorOptions {
+            preview_data_format: PreviewDataFormat::U16,
+            endianness: crate::options::Endianness::Little,
+            ..MemoryEditorOptions::default()
+        };
+
+        assert_eq!(
+            typed_value_writes(&content, 0x10, "0x1234", &options),
+            Ok(vec![(0x10, 0x34), (0x11, 0x12)])
+        );
+        assert_eq!(
+            typed_value_writes(&content, 0x10, "12g", &options),
+            Err(Status::InvalidValue)
+        );
+        assert_eq!(
+            typed_value_writes(&content, 0x10, "65536", &options),
+            Err(Status::OutOfRange)
+        );
+        assert_eq!(
+            typed_value_writes(&content, 0xFF, "1", &options),
+            Err(Status::NotWritable)
+        );
+    }
+
+    #[test]
+    fn replacements_skip_overlaps() {
+        let content = content();
+        let (writes, replaced, skipped) =
+            replacement_writes(&content, &[0x10, 0x11, 0x12, 0xFF], &[Some(0xAA), None]);
+
+        assert_eq!(writes, vec![(0x10, 0xAA), (0x12, 0xAA)]);
+        assert_eq!((replaced, skipped), (2, 2));
+    }
+
+    #[test]
+    fn operations_in_controlled_mode() {
+        let content = content();
+        let id = Id::new("memory");
+        let mut state = State::default();
+        state.dimensions.row_count = 4;
+        let bindings = Bindings {
+            on_select: Some(Box::new(Message::Selected)),
+            on_scroll: Some(Box::new(Message::Scrolled)),
+            start_address: Some(0),
+            selection: Some(None),
+            ..Bindings::default()
+        };
+
+        run(&mut scroll_to(id.clone(), 0x25), &id, &mut state);
+        run(&mut select(id.clone(), 0x10..0x12), &id, &mut state);
+        let messages = apply(&content, &mut state, &bindings);
+
+        assert_eq!(state.start_address, 0);
+        assert_eq!(state.selection, None);
+        assert_eq!(
+            messages,
+            vec![
+                Message::Scrolled(0x20),
+                Message::Selected(Selection::from_range(0x10..0x12))
+            ]
+        );
+    }
+}
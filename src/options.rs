@@ -7,12 +7,20 @@ pub enum PreviewDataFormat {
     U16,
     U32,
     U64,
+    U128,
     I8,
     I16,
     I32,
     I64,
+    I128,
+    F16,
+    BF16,
     F32,
     F64,
+    Uleb128,
+    Sleb128,
+    Bcd,
+    Bitfield,
 }
 
 impl Display for PreviewDataFormat {
@@ -25,63 +33,422 @@ impl Display for PreviewDataFormat {
                 PreviewDataFormat::U16 => "U16",
                 PreviewDataFormat::U32 => "U32",
                 PreviewDataFormat::U64 => "U64",
+                PreviewDataFormat::U128 => "U128",
                 PreviewDataFormat::I8 => "I8",
                 PreviewDataFormat::I16 => "I16",
                 PreviewDataFormat::I32 => "I32",
                 PreviewDataFormat::I64 => "I64",
+                PreviewDataFormat::I128 => "I128",
+                PreviewDataFormat::F16 => "F16",
+                PreviewDataFormat::BF16 => "BF16",
                 PreviewDataFormat::F32 => "F32",
                 PreviewDataFormat::F64 => "F64",
+                PreviewDataFormat::Uleb128 => "ULEB128",
+                PreviewDataFormat::Sleb128 => "SLEB128",
+                PreviewDataFormat::Bcd => "BCD",
+                PreviewDataFormat::Bitfield => "Bits",
             }
         )
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
-pub struct MemoryEditorOptions {
-    pub row_length: usize,
-    pub preview_data_format: PreviewDataFormat,
-    pub show_ascii: bool,
-}
+impl PreviewDataFormat {
+    pub fn size(&self) -> usize {
+        match self {
+            PreviewDataFormat::U8 | PreviewDataFormat::I8 => 1,
+            PreviewDataFormat::U16
+            | PreviewDataFormat::I16
+            | PreviewDataFormat::F16
+            | PreviewDataFormat::BF16
+            | PreviewDataFormat::Bitfield => 2,
+            PreviewDataFormat::U32
+            | PreviewDataFormat::I32
+            | PreviewDataFormat::F32
+            | PreviewDataFormat::Bcd => 4,
+            PreviewDataFormat::U64 | PreviewDataFormat::I64 | PreviewDataFormat::F64 => 8,
+            PreviewDataFormat::U128 | PreviewDataFormat::I128 => 16,
+            PreviewDataFormat::Uleb128 | PreviewDataFormat::Sleb128 => 10,
+        }
+    }
 
-impl Default for MemoryEditorOptions {
-    fn default() -> Self {
-        Self {
-            row_length: 16,
-            preview_data_format: PreviewDataFormat::default(),
-            show_ascii: true,
+    /// Varints take up to `size()` bytes, and are never aligned.
+    pub fn is_variable_length(&self) -> bool {
+        matches!(
+            self,
+            PreviewDataFormat::Uleb128 | PreviewDataFormat::Sleb128
+        )
+    }
+
+    pub fn alignment(&self) -> usize {
+        if self.is_variable_length() {
+            1
+        } else {
+            self.size()
         }
     }
-}
 
-impl MemoryEditorOptions {
-    pub fn previous_data_format(&self) -> PreviewDataFormat {
-        match self.preview_data_format {
-            PreviewDataFormat::U8 => PreviewDataFormat::F64,
+    pub fn is_float(&self) -> bool {
+        matches!(
+            self,
+            PreviewDataFormat::F16
+                | PreviewDataFormat::BF16
+                | PreviewDataFormat::F32
+                | PreviewDataFormat::F64
+        )
+    }
+
+    pub fn previous(&self) -> PreviewDataFormat {
+        match self {
+            PreviewDataFormat::U8 => PreviewDataFormat::Bitfield,
             PreviewDataFormat::U16 => PreviewDataFormat::U8,
             PreviewDataFormat::U32 => PreviewDataFormat::U16,
             PreviewDataFormat::U64 => PreviewDataFormat::U32,
-            PreviewDataFormat::I8 => PreviewDataFormat::U64,
+            PreviewDataFormat::U128 => PreviewDataFormat::U64,
+            PreviewDataFormat::I8 => PreviewDataFormat::U128,
             PreviewDataFormat::I16 => PreviewDataFormat::I8,
             PreviewDataFormat::I32 => PreviewDataFormat::I16,
             PreviewDataFormat::I64 => PreviewDataFormat::I32,
-            PreviewDataFormat::F32 => PreviewDataFormat::I64,
+            PreviewDataFormat::I128 => PreviewDataFormat::I64,
+            PreviewDataFormat::F16 => PreviewDataFormat::I128,
+            PreviewDataFormat::BF16 => PreviewDataFormat::F16,
+            PreviewDataFormat::F32 => PreviewDataFormat::BF16,
             PreviewDataFormat::F64 => PreviewDataFormat::F32,
+            PreviewDataFormat::Uleb128 => PreviewDataFormat::F64,
+            PreviewDataFormat::Sleb128 => PreviewDataFormat::Uleb128,
+            PreviewDataFormat::Bcd => PreviewDataFormat::Sleb128,
+            PreviewDataFormat::Bitfield => PreviewDataFormat::Bcd,
         }
     }
 
-    pub fn next_data_format(&self) -> PreviewDataFormat {
-        match self.preview_data_format {
+    pub fn next(&self) -> PreviewDataFormat {
+        match self {
             PreviewDataFormat::U8 => PreviewDataFormat::U16,
             PreviewDataFormat::U16 => PreviewDataFormat::U32,
             PreviewDataFormat::U32 => PreviewDataFormat::U64,
-            PreviewDataFormat::U64 => PreviewDataFormat::I8,
+            PreviewDataFormat::U64 => PreviewDataFormat::U128,
+            PreviewDataFormat::U128 => PreviewDataFormat::I8,
             PreviewDataFormat::I8 => PreviewDataFormat::I16,
             PreviewDataFormat::I16 => PreviewDataFormat::I32,
             PreviewDataFormat::I32 => PreviewDataFormat::I64,
-            PreviewDataFormat::I64 => PreviewDataFormat::F32,
+            PreviewDataFormat::I64 => PreviewDataFormat::I128,
+            PreviewDataFormat::I128 => PreviewDataFormat::F16,
+            PreviewDataFormat::F16 => PreviewDataFormat::BF16,
+            PreviewDataFormat::BF16 => PreviewDataFormat::F32,
             PreviewDataFormat::F32 => PreviewDataFormat::F64,
-            PreviewDataFormat::F64 => PreviewDataFormat::U8,
+            PreviewDataFormat::F64 => PreviewDataFormat::Uleb128,
+            PreviewDataFormat::Uleb128 => PreviewDataFormat::Sleb128,
+            PreviewDataFormat::Sleb128 => PreviewDataFormat::Bcd,
+            PreviewDataFormat::Bcd => PreviewDataFormat::Bitfield,
+            PreviewDataFormat::Bitfield => PreviewDataFormat::U8,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Copy, PartialEq)]
+pub enum Endianness {
+    Little,
+    Big,
+    #[default]
+    Native,
+}
+
+impl Display for Endianness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Endianness::Little => "Little",
+                Endianness::Big => "Big",
+                Endianness::Native => "Native",
+            }
+        )
+    }
+}
+
+impl Endianness {
+    pub fn is_little(&self) -> bool {
+        match self {
+            Endianness::Little => true,
+            Endianness::Big => false,
+            Endianness::Native => cfg!(target_endian = "little"),
+        }
+    }
+
+    pub fn previous(&self) -> Endianness {
+        match self {
+            Endianness::Little => Endianness::Native,
+            Endianness::Big => Endianness::Little,
+            Endianness::Native => Endianness::Big,
+        }
+    }
+
+    pub fn next(&self) -> Endianness {
+        match self {
+            Endianness::Little => Endianness::Big,
+            Endianness::Big => Endianness::Native,
+            Endianness::Native => Endianness::Little,
         }
     }
 }
 
+#[derive(Clone, Debug, Default, Copy, PartialEq)]
+pub enum FloatFormat {
+    #[default]
+    Shortest,
+    Scientific,
+    Fixed,
+    HexFloat,
+}
+
+impl Display for FloatFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                FloatFormat::Shortest => "Shortest",
+                FloatFormat::Scientific => "Sci",
+                FloatFormat::Fixed => "Fixed",
+                FloatFormat::HexFloat => "Hex",
+            }
+        )
+    }
+}
+
+impl FloatFormat {
+    pub fn has_precision(&self) -> bool {
+        matches!(self, FloatFormat::Scientific | FloatFormat::Fixed)
+    }
+
+    pub fn previous(&self) -> FloatFormat {
+        match self {
+            FloatFormat::Shortest => FloatFormat::HexFloat,
+            FloatFormat::Scientific => FloatFormat::Shortest,
+            FloatFormat::Fixed => FloatFormat::Scientific,
+            FloatFormat::HexFloat => FloatFormat::Fixed,
+        }
+    }
+
+    pub fn next(&self) -> FloatFormat {
+        match self {
+            FloatFormat::Shortest => FloatFormat::Scientific,
+            FloatFormat::Scientific => FloatFormat::Fixed,
+            FloatFormat::Fixed => FloatFormat::HexFloat,
+            FloatFormat::HexFloat => FloatFormat::Shortest,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Copy, PartialEq)]
+pub enum CellRadix {
+    #[default]
+    HexLower,
+    HexUpper,
+    Decimal,
+    Octal,
+    Binary,
+}
+
+impl Display for CellRadix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                CellRadix::HexLower => "hex",
+                CellRadix::HexUpper => "HEX",
+                CellRadix::Decimal => "Dec",
+                CellRadix::Octal => "Oct",
+                CellRadix::Binary => "Bin",
+            }
+        )
+    }
+}
+
+impl CellRadix {
+    pub fn radix(&self) -> u32 {
+        match self {
+            CellRadix::HexLower | CellRadix::HexUpper => 16,
+            CellRadix::Decimal => 10,
+            CellRadix::Octal => 8,
+            CellRadix::Binary => 2,
+        }
+    }
+
+    /// Number of digits a cell of `bytes` bytes takes.
+    pub fn digits(&self, bytes: usize) -> usize {
+        let max = u64::MAX >> (64 - bytes * 8);
+        max.ilog(self.radix() as u64) as usize + 1
+    }
+
+    pub fn format_word(&self, value: u64, bytes: usize) -> String {
+        let width = self.digits(bytes);
+        match self {
+            CellRadix::HexLower => format!("{:0width$x}", value),
+            CellRadix::HexUpper => format!("{:0width$X}", value),
+            CellRadix::Decimal => format!("{:0width$}", value),
+            CellRadix::Octal => format!("{:0width$o}", value),
+            CellRadix::Binary => format!("{:0width$b}", value),
+        }
+    }
+
+    pub fn previous(&self) -> CellRadix {
+        match self {
+            CellRadix::HexLower => CellRadix::Binary,
+            CellRadix::HexUpper => CellRadix::HexLower,
+            CellRadix::Decimal => CellRadix::HexUpper,
+            CellRadix::Octal => CellRadix::Decimal,
+            CellRadix::Binary => CellRadix::Octal,
+        }
+    }
+
+    pub fn next(&self) -> CellRadix {
+        match self {
+            CellRadix::HexLower => CellRadix::HexUpper,
+            CellRadix::HexUpper => CellRadix::Decimal,
+            CellRadix::Decimal => CellRadix::Octal,
+            CellRadix::Octal => CellRadix::Binary,
+            CellRadix::Binary => CellRadix::HexLower,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Copy, PartialEq)]
+pub enum CellSize {
+    #[default]
+    U8,
+    U16,
+    U32,
+    U64,
+}
+
+impl Display for CellSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CellSize::U8 => write!(f, "1 byte"),
+            _ => write!(f, "{} bytes", self.bytes()),
+        }
+    }
+}
+
+impl CellSize {
+    pub fn bytes(&self) -> usize {
+        match self {
+            CellSize::U8 => 1,
+            CellSize::U16 => 2,
+            CellSize::U32 => 4,
+            CellSize::U64 => 8,
+        }
+    }
+
+    pub fn format(&self) -> PreviewDataFormat {
+        match self {
+            CellSize::U8 => PreviewDataFormat::U8,
+            CellSize::U16 => PreviewDataFormat::U16,
+            CellSize::U32 => PreviewDataFormat::U32,
+            CellSize::U64 => PreviewDataFormat::U64,
+        }
+    }
+
+    pub fn previous(&self) -> CellSize {
+        match self {
+            CellSize::U8 => CellSize::U64,
+            CellSize::U16 => CellSize::U8,
+            CellSize::U32 => CellSize::U16,
+            CellSize::U64 => CellSize::U32,
+        }
+    }
+
+    pub fn next(&self) -> CellSize {
+        match self {
+            CellSize::U8 => CellSize::U16,
+            CellSize::U16 => CellSize::U32,
+            CellSize::U32 => CellSize::U64,
+            CellSize::U64 => CellSize::U8,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Copy, PartialEq)]
+pub enum CopyFormat {
+    #[default]
+    Hex,
+    Ascii,
+    CArray,
+    RustArray,
+}
+
+impl Display for CopyFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                CopyFormat::Hex => "Hex",
+                CopyFormat::Ascii => "ASCII",
+                CopyFormat::CArray => "C",
+                CopyFormat::RustArray => "Rust",
+            }
+        )
+    }
+}
+
+impl CopyFormat {
+    pub fn previous(&self) -> CopyFormat {
+        match self {
+            CopyFormat::Hex => CopyFormat::RustArray,
+            CopyFormat::Ascii => CopyFormat::Hex,
+            CopyFormat::CArray => CopyFormat::Ascii,
+            CopyFormat::RustArray => CopyFormat::CArray,
+        }
+    }
+
+    pub fn next(&self) -> CopyFormat {
+        match self {
+            CopyFormat::Hex => CopyFormat::Ascii,
+            CopyFormat::Ascii => CopyFormat::CArray,
+            CopyFormat::CArray => CopyFormat::RustArray,
+            CopyFormat::RustArray => CopyFormat::Hex,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MemoryEditorOptions {
+    pub row_length: usize,
+    pub preview_data_format: PreviewDataFormat,
+    pub show_ascii: bool,
+    pub copy_format: CopyFormat,
+    pub endianness: Endianness,
+    pub float_format: FloatFormat,
+    pub float_precision: usize,
+    pub cell_radix: CellRadix,
+    pub cell_size: CellSize,
+}
+
+impl Default for MemoryEditorOptions {
+    fn default() -> Self {
+        Self {
+            row_length: 16,
+            preview_data_format: PreviewDataFormat::default(),
+            show_ascii: true,
+            copy_format: CopyFormat::default(),
+            endianness: Endianness::default(),
+            float_format: FloatFormat::default(),
+            float_precision: 3,
+            cell_radix: CellRadix::default(),
+            cell_size: CellSize::default(),
+        }
+    }
+}
+
+impl MemoryEditorOptions {
+    pub fn previous_data_format(&self) -> PreviewDataFormat {
+        self.preview_data_format.previous()
+    }
+
+    pub fn next_data_format(&self) -> PreviewDataFormat {
+        self.preview_data_format.next()
+    }
+}
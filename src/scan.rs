@@ -0,0 +1,448 @@
+use crate::options::{Endianness, MemoryEditorOptions, PreviewDataFormat};
+use crate::search::{CHUNKS_PER_STEP, CHUNK_SIZE};
+use crate::value::{self, Value};
+use std::{cmp::Ordering, fmt, ops::Range};
+
+const MAX_CANDIDATES: usize = 1 << 22;
+
+#[derive(Clone, Debug, Default, Copy, PartialEq)]
+pub enum ScanFilter {
+    #[default]
+    Changed,
+    Unchanged,
+    Increased,
+    Decreased,
+    Equals,
+}
+
+impl fmt::Display for ScanFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                ScanFilter::Changed => "Changed",
+                ScanFilter::Unchanged => "Same",
+                ScanFilter::Increased => "Greater",
+                ScanFilter::Decreased => "Less",
+                ScanFilter::Equals => "Equals",
+            }
+        )
+    }
+}
+
+impl ScanFilter {
+    pub fn previous(&self) -> ScanFilter {
+        match self {
+            ScanFilter::Changed => ScanFilter::Equals,
+            ScanFilter::Unchanged => ScanFilter::Changed,
+            ScanFilter::Increased => ScanFilter::Unchanged,
+            ScanFilter::Decreased => ScanFilter::Increased,
+            ScanFilter::Equals => ScanFilter::Decreased,
+        }
+    }
+
+    pub fn next(&self) -> ScanFilter {
+        match self {
+            ScanFilter::Changed => ScanFilter::Unchanged,
+            ScanFilter::Unchanged => ScanFilter::Increased,
+            ScanFilter::Increased => ScanFilter::Decreased,
+            ScanFilter::Decreased => ScanFilter::Equals,
+            ScanFilter::Equals => ScanFilter::Changed,
+        }
+    }
+
+    fn accepts(
+        &self,
+        previous: &Value,
+        current: &Value,
+        target: Option<&Value>,
+        epsilon: f64,
+    ) -> bool {
+        match self {
+            ScanFilter::Changed => !current.approx_eq(previous, 0.0),
+            ScanFilter::Unchanged => current.approx_eq(previous, 0.0),
+            ScanFilter::Increased => current.partial_cmp(previous) == Some(Ordering::Greater),
+            ScanFilter::Decreased => current.partial_cmp(previous) == Some(Ordering::Less),
+            ScanFilter::Equals => target.is_some_and(|target| current.approx_eq(target, epsilon)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Candidate {
+    pub address: usize,
+    pub value: Value,
+}
+
+/// Candidate addresses along with the raw bytes of their value, `size` bytes each.
+#[derive(Default)]
+struct Candidates {
+    addresses: Vec<usize>,
+    bytes: Vec<u8>,
+}
+
+impl Candidates {
+    fn push(&mut self, address: usize, data: &[Option<u8>], size: usize) {
+        self.addresses.push(address);
+        self.bytes.extend(
+            (0..size).map(|offset| data.get(offset).copied().flatten().unwrap_or_default()),
+        );
+    }
+}
+
+enum Pass {
+    First {
+        range: Range<usize>,
+        position: usize,
+        target: Option<Value>,
+        epsilon: f64,
+    },
+    Next {
+        filter: ScanFilter,
+        target: Option<Value>,
+        epsilon: f64,
+        index: usize,
+        kept: Candidates,
+    },
+}
+
+/// Narrows down the addresses holding a value over successive passes, each
+/// pass reading a bounded number of chunks per step.
+pub(crate) struct Scanner {
+    format: PreviewDataFormat,
+    endianness: Endianness,
+    alignment: usize,
+    end: usize,
+    candidates: Candidates,
+    truncated: bool,
+    pass: Option<Pass>,
+}
+
+impl Scanner {
+    /// Starts a scan for `target`, or for every readable value when it is `None`.
+    pub(crate) fn first(
+        format: PreviewDataFormat,
+        endianness: Endianness,
+        aligned: bool,
+        range: Range<usize>,
+        target: Option<Value>,
+        epsilon: f64,
+    ) -> Self {
+        Self {
+            format,
+            endianness,
+            alignment: if aligned { format.alignment() } else { 1 },
+            end: range.end,
+            candidates: Candidates::default(),
+            truncated: false,
+            pass: Some(Pass::First {
+                position: range.start,
+                range,
+                target,
+                epsilon,
+            }),
+        }
+    }
+
+    pub(crate) fn next(&mut self, filter: ScanFilter, target: Option<Value>, epsilon: f64) {
+        self.pass = Some(Pass::Next {
+            filter,
+            target,
+            epsilon,
+            index: 0,
+            kept: Candidates::default(),
+        });
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.candidates.addresses.len()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.candidates.addresses.is_empty()
+    }
+
+    /// True when the first pass found more than `MAX_CANDIDATES` and kept the first ones.
+    pub(crate) fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    pub(crate) fn get(&self, index: usize) -> Option<Candidate> {
+        let address = *self.candidates.addresses.get(index)?;
+        let size = self.format.size();
+        let bytes = &self.candidates.bytes[index * size..(index + 1) * size];
+        Some(Candidate {
+            address,
+            value: value::decode(bytes, self.format, self.endianness)?,
+        })
+    }
+
+    /// The candidates from `start` on, along with their value at the last scan.
+    pub(crate) fn candidates(&self, start: usize) -> impl Iterator<Item = Candidate> + '_ {
+        (start..self.len()).filter_map(|index| self.get(index))
+    }
+
+    /// The addresses to read to get the current value of the candidate at `address`.
+    pub(crate) fn value_range(&self, address: usize) -> Range<usize> {
+        address..(address + self.format.size()).min(self.end)
+    }
+
+    pub(crate) fn is_running(&self) -> bool {
+        self.pass.is_some()
+    }
+
+    pub(crate) fn format(&self) -> PreviewDataFormat {
+        self.format
+    }
+
+    pub(crate) fn value_at(&self, data: &[Option<u8>]) -> Option<Value> {
+        let bytes: Vec<u8> = data[..self.format.size().min(data.len())]
+            .iter()
+            .copied()
+            .collect::<Option<_>>()?;
+        value::decode(&bytes, self.format, self.endianness)
+    }
+
+    pub(crate) fn display(&self, value: Value, options: &MemoryEditorOptions) -> String {
+        value::encode(value, self.format, self.endianness)
+            .and_then(|bytes| {
+                value::format_float(
+                    &bytes,
+                    self.format,
+                    self.endianness,
+                    options.float_format,
+                    options.float_precision,
+                )
+            })
+            .unwrap_or_else(|| value.to_string())
+    }
+
+    /// Returns true once the current pass is complete.
+    pub(crate) fn step(&mut self, read: impl Fn(Range<usize>) -> Vec<Option<u8>>) -> bool {
+        let size = self.format.size();
+
+        for _ in 0..CHUNKS_PER_STEP {
+            match self.pass.take() {
+                None => return true,
+                Some(Pass::First {
+                    range,
+                    position,
+                    target,
+                    epsilon,
+                }) => {
+                    let end = (position + CHUNK_SIZE).min(range.end);
+                    let data = read(position..(end + size - 1).min(range.end));
+
+                    for address in (position..end).filter(|a| a.is_multiple_of(self.alignment)) {
+                        let Some(value) = data
+                            .get(address - position..)
+                            .and_then(|d| self.value_at(d))
+                        else {
+                            continue;
+                        };
+                        if target.is_none_or(|target| value.approx_eq(&target, epsilon)) {
+                            if self.len() == MAX_CANDIDATES {
+                                self.truncated = true;
+                                break;
+                            }
+                            self.candidates
+                                .push(address, &data[address - position..], size);
+                        }
+                    }
+
+                    if end < range.end && !self.truncated {
+                        self.pass = Some(Pass::First {
+                            range,
+                            position: end,
+                            target,
+                            epsilon,
+                        });
+                    }
+                }
+                Some(Pass::Next {
+                    filter,
+                    target,
+                    epsilon,
+                    mut index,
+                    mut kept,
+                }) => {
+                    let Some(&start) = self.candidates.addresses.get(index) else {
+                        self.candidates = kept;
+                        return true;
+                    };
+
+                    let batch = self.candidates.addresses[index..]
+                        .iter()
+                        .take_while(|&&address| address + size <= start + CHUNK_SIZE)
+                        .count();
+                    let last = self.candidates.addresses[index + batch - 1];
+                    let data = read(start..(last + size).min(self.end));
+
+                    for candidate in index..index + batch {
+                        let Some(previous) = self.get(candidate) else {
+                            continue;
+                        };
+                        let Some(current) = data.get(previous.address - start..) else {
+                            continue;
+                        };
+                        let Some(value) = self.value_at(current) else {
+                            continue;
+                        };
+                        if filter.accepts(&previous.value, &value, target.as_ref(), epsilon) {
+                            kept.push(previous.address, current, size);
+                        }
+                    }
+
+                    index += batch;
+                    self.pass = Some(Pass::Next {
+                        filter,
+                        target,
+                        epsilon,
+                        index,
+                        kept,
+                    });
+                }
+            }
+        }
+
+        self.pass.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(scanner: &mut Scanner, data: &[Option<u8>]) {
+        while !scanner.step(|range| data[range].to_vec()) {}
+    }
+
+    fn addresses(scanner: &Scanner) -> Vec<usize> {
+        scanner
+            .candidates(0)
+            .map(|candidate| candidate.address)
+            .collect()
+    }
+
+    fn bytes(data: &[u8]) -> Vec<Option<u8>> {
+        data.iter().copied().map(Some).collect()
+    }
+
+    #[test]
+    fn first_pass() {
+        let mut data = bytes(&[0x34, 0x12, 0x00, 0x34, 0x12, 0x34, 0x12, 0x00]);
+        let target = Some(Value::Unsigned(0x1234));
+        let first = |aligned, target| {
+            Scanner::first(
+                PreviewDataFormat::U16,
+                Endianness::Little,
+                aligned,
+                0..8,
+                target,
+                0.0,
+            )
+        };
+
+        let mut scanner = first(false, target);
+        run(&mut scanner, &data);
+        assert_eq!(addresses(&scanner), [0, 3, 5]);
+        assert_eq!(scanner.get(1).map(|c| c.value), target);
+
+        let mut scanner = first(true, target);
+        run(&mut scanner, &data);
+        assert_eq!(addresses(&scanner), [0]);
+
+        // Every readable value when there is no target
+        data[4] = None;
+        let mut scanner = first(false, None);
+        run(&mut scanner, &data);
+        assert_eq!(addresses(&scanner), [0, 1, 2, 5, 6]);
+        assert!(!scanner.is_truncated());
+    }
+
+    #[test]
+    fn next_pass() {
+        let mut data = bytes(&[1, 2, 3, 4, 5]);
+        let mut scanner = Scanner::first(
+            PreviewDataFormat::U8,
+            Endianness::Little,
+            false,
+            0..data.len(),
+            None,
+            0.0,
+        );
+        run(&mut scanner, &data);
+
+        data[1] = Some(1);
+        data[2] = Some(9);
+        data[3] = None;
+        scanner.next(ScanFilter::Changed, None, 0.0);
+        run(&mut scanner, &data);
+        assert_eq!(addresses(&scanner), [1, 2]);
+        assert_eq!(scanner.get(0).map(|c| c.value), Some(Value::Unsigned(1)));
+    }
+
+    #[test]
+    fn filters() {
+        let (one, two) = (Value::Unsigned(1), Value::Unsigned(2));
+        let accepts = |filter: ScanFilter, previous, current| {
+            filter.accepts(&previous, &current, Some(&two), 0.0)
+        };
+
+        assert!(accepts(ScanFilter::Changed, one, two));
+        assert!(!accepts(ScanFilter::Changed, one, one));
+        assert!(accepts(ScanFilter::Unchanged, one, one));
+        assert!(!accepts(ScanFilter::Unchanged, one, two));
+        assert!(accepts(ScanFilter::Increased, one, two));
+        assert!(!accepts(ScanFilter::Increased, two, one));
+        assert!(!accepts(ScanFilter::Increased, one, one));
+        assert!(accepts(ScanFilter::Decreased, two, one));
+        assert!(!accepts(ScanFilter::Decreased, one, two));
+        assert!(!accepts(ScanFilter::Decreased, one, one));
+        assert!(accepts(ScanFilter::Equals, one, two));
+        assert!(!accepts(ScanFilter::Equals, two, one));
+        assert!(!ScanFilter::Equals.accepts(&one, &two, None, 0.0));
+    }
+
+    #[test]
+    fn float_filters() {
+        let (a, b, nan) = (Value::Float(1.0), Value::Float(1.5), Value::Float(f64::NAN));
+
+        assert!(ScanFilter::Equals.accepts(&a, &b, Some(&Value::Float(1.4)), 0.2));
+        assert!(!ScanFilter::Equals.accepts(&a, &b, Some(&Value::Float(1.4)), 0.05));
+        assert!(ScanFilter::Increased.accepts(&a, &b, None, 0.0));
+        // NaN never equals anything, itself included, so it always counts as changed
+        assert!(ScanFilter::Changed.accepts(&nan, &nan, None, 0.0));
+        assert!(ScanFilter::Changed.accepts(&a, &nan, None, 0.0));
+        assert!(!ScanFilter::Unchanged.accepts(&nan, &nan, None, 0.0));
+        assert!(!ScanFilter::Increased.accepts(&a, &nan, None, 0.0));
+        assert!(!ScanFilter::Decreased.accepts(&nan, &a, None, 0.0));
+        assert!(!ScanFilter::Equals.accepts(&a, &nan, Some(&nan), 1.0));
+    }
+
+    #[test]
+    fn truncation() {
+        let scan = |len| {
+            let data = vec![Some(0); len];
+            let mut scanner = Scanner::first(
+                PreviewDataFormat::U8,
+                Endianness::Little,
+                false,
+                0..len,
+                None,
+                0.0,
+            );
+            run(&mut scanner, &data);
+            scanner
+        };
+
+        let scanner = scan(MAX_CANDIDATES);
+        assert_eq!(scanner.len(), MAX_CANDIDATES);
+        assert!(!scanner.is_truncated());
+
+        let scanner = scan(MAX_CANDIDATES + 1);
+        assert_eq!(scanner.len(), MAX_CANDIDATES);
+        assert!(scanner.is_truncated());
+    }
+}
@@ -0,0 +1,707 @@
+use crate::options::{Endianness, PreviewDataFormat};
+use crate::value::{self, Value};
+use std::{fmt, ops::Range};
+
+pub(crate) const CHUNK_SIZE: usize = 0x10000;
+pub(crate) const CHUNKS_PER_STEP: usize = 4;
+pub(crate) const MAX_RESULTS: usize = 100_000;
+
+#[derive(Clone, Debug, Default, Copy, PartialEq)]
+pub enum SearchMode {
+    #[default]
+    Hex,
+    Text,
+    Value,
+}
+
+impl fmt::Display for SearchMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                SearchMode::Hex => "Hex",
+                SearchMode::Text => "Text",
+                SearchMode::Value => "Value",
+            }
+        )
+    }
+}
+
+impl SearchMode {
+    pub fn previous(&self) -> SearchMode {
+        match self {
+            SearchMode::Hex => SearchMode::Value,
+            SearchMode::Text => SearchMode::Hex,
+            SearchMode::Value => SearchMode::Text,
+        }
+    }
+
+    pub fn next(&self) -> SearchMode {
+        match self {
+            SearchMode::Hex => SearchMode::Text,
+            SearchMode::Text => SearchMode::Value,
+            SearchMode::Value => SearchMode::Hex,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Copy, PartialEq)]
+pub enum TextEncoding {
+    #[default]
+    Ascii,
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+}
+
+impl fmt::Display for TextEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                TextEncoding::Ascii => "ASCII",
+                TextEncoding::Utf8 => "UTF-8",
+                TextEncoding::Utf16Le => "UTF-16LE",
+                TextEncoding::Utf16Be => "UTF-16BE",
+            }
+        )
+    }
+}
+
+impl TextEncoding {
+    pub fn previous(&self) -> TextEncoding {
+        match self {
+            TextEncoding::Ascii => TextEncoding::Utf16Be,
+            TextEncoding::Utf8 => TextEncoding::Ascii,
+            TextEncoding::Utf16Le => TextEncoding::Utf8,
+            TextEncoding::Utf16Be => TextEncoding::Utf16Le,
+        }
+    }
+
+    pub fn next(&self) -> TextEncoding {
+        match self {
+            TextEncoding::Ascii => TextEncoding::Utf8,
+            TextEncoding::Utf8 => TextEncoding::Utf16Le,
+            TextEncoding::Utf16Le => TextEncoding::Utf16Be,
+            TextEncoding::Utf16Be => TextEncoding::Ascii,
+        }
+    }
+
+    pub fn encode_str(&self, text: &str) -> Option<Vec<u8>> {
+        let mut bytes = Vec::new();
+        for c in text.chars() {
+            bytes.extend(self.encode(c)?);
+        }
+        (!bytes.is_empty()).then_some(bytes)
+    }
+
+    fn encode(&self, c: char) -> Option<Vec<u8>> {
+        let mut buffer = [0u16; 2];
+        match self {
+            TextEncoding::Ascii => c.is_ascii().then(|| vec![c as u8]),
+            TextEncoding::Utf8 => Some(c.to_string().into_bytes()),
+            TextEncoding::Utf16Le => Some(
+                c.encode_utf16(&mut buffer)
+                    .iter()
+                    .flat_map(|unit| unit.to_le_bytes())
+                    .collect(),
+            ),
+            TextEncoding::Utf16Be => Some(
+                c.encode_utf16(&mut buffer)
+                    .iter()
+                    .flat_map(|unit| unit.to_be_bytes())
+                    .collect(),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern {
+    Bytes(Vec<Option<u8>>),
+    /// The accepted encodings of each character, all of the same length.
+    Text(Vec<Vec<Vec<u8>>>),
+    Value {
+        format: PreviewDataFormat,
+        endianness: Endianness,
+        value: Value,
+        epsilon: f64,
+        aligned: bool,
+    },
+}
+
+impl Pattern {
+    /// Parses space separated hex bytes, `??` matches any readable byte.
+    pub fn parse_hex(text: &str) -> Option<Self> {
+        let mut bytes = Vec::new();
+
+        for token in text.split_whitespace() {
+            if token == "?" {
+                bytes.push(None);
+                continue;
+            }
+
+            if token.len() % 2 != 0 {
+                return None;
+            }
+            for pair in token.as_bytes().chunks(2) {
+                let pair = std::str::from_utf8(pair).ok()?;
+                bytes.push(match pair {
+                    "??" => None,
+                    _ => Some(u8::from_str_radix(pair, 16).ok()?),
+                });
+            }
+        }
+
+        (!bytes.is_empty()).then_some(Pattern::Bytes(bytes))
+    }
+
+    /// Encodes the text, with simple case folding unless `match_case` is set.
+    pub fn parse_text(text: &str, encoding: TextEncoding, match_case: bool) -> Option<Self> {
+        let mut chars = Vec::new();
+
+        for c in text.chars() {
+            let encoded = encoding.encode(c)?;
+            let mut variants = vec![encoded];
+
+            if !match_case {
+                let cases = [single_char(c.to_lowercase()), single_char(c.to_uppercase())];
+                for case in cases.into_iter().flatten() {
+                    if let Some(encoded) = encoding.encode(case) {
+                        if encoded.len() == variants[0].len() && !variants.contains(&encoded) {
+                            variants.push(encoded);
+                        }
+                    }
+                }
+            }
+
+            chars.push(variants);
+        }
+
+        (!chars.is_empty()).then_some(Pattern::Text(chars))
+    }
+
+    pub fn parse_value(
+        text: &str,
+        format: PreviewDataFormat,
+        endianness: Endianness,
+        epsilon: f64,
+        aligned: bool,
+    ) -> Option<Self> {
+        let value = value::parse(text, format)?;
+        if format.is_variable_length() {
+            let bytes = value::encode(value, format, endianness)?;
+            return Some(Pattern::Bytes(bytes.into_iter().map(Some).collect()));
+        }
+        Some(Pattern::Value {
+            format,
+            endianness,
+            value,
+            epsilon,
+            aligned,
+        })
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Pattern::Bytes(bytes) => bytes.len(),
+            Pattern::Text(chars) => chars.iter().map(|variants| variants[0].len()).sum(),
+            Pattern::Value { format, .. } => format.size(),
+        }
+    }
+
+    pub(crate) fn alignment(&self) -> usize {
+        match self {
+            Pattern::Value {
+                format,
+                aligned: true,
+                ..
+            } => format.alignment(),
+            _ => 1,
+        }
+    }
+
+    pub(crate) fn matches(&self, data: &[Option<u8>]) -> bool {
+        match self {
+            Pattern::Bytes(bytes) => {
+                data.len() >= bytes.len()
+                    && bytes.iter().zip(data).all(|(expected, byte)| match byte {
+                        Some(byte) => expected.is_none_or(|expected| expected == *byte),
+                        None => false,
+                    })
+            }
+            Pattern::Text(chars) => {
+                let mut offset = 0;
+                chars.iter().all(|variants| {
+                    let len = variants[0].len();
+                    let Some(bytes) = data.get(offset..offset + len) else {
+                        return false;
+                    };
+                    offset += len;
+                    variants.iter().any(|variant| {
+                        variant
+                            .iter()
+                            .zip(bytes)
+                            .all(|(expected, byte)| Some(*expected) == *byte)
+                    })
+                })
+            }
+            Pattern::Value {
+                format,
+                endianness,
+                value,
+                epsilon,
+                ..
+            } => {
+                let Some(bytes) = data
+                    .get(..format.size())
+                    .and_then(|bytes| bytes.iter().copied().collect::<Option<Vec<u8>>>())
+                else {
+                    return false;
+                };
+                value::decode(&bytes, *format, *endianness)
+                    .is_some_and(|decoded| decoded.approx_eq(value, *epsilon))
+            }
+        }
+    }
+}
+
+fn single_char(mut chars: impl Iterator<Item = char>) -> Option<char> {
+    let c = chars.next()?;
+    chars.next().is_none().then_some(c)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Forward,
+    Backward,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum Status {
+    #[default]
+    Idle,
+    Invalid,
+    Searching,
+    Found(usize),
+    NotFound,
+    Matches(usize),
+    Replaced {
+        replaced: usize,
+        skipped: usize,
+        truncated: bool,
+    },
+    Scanning,
+    Candidates {
+        count: usize,
+        truncated: bool,
+    },
+    InvalidValue,
+    OutOfRange,
+    NotWritable,
+    InvalidHex,
+    CopyTooLarge,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Idle => Ok(()),
+            Status::Invalid => write!(f, "Invalid pattern"),
+            Status::Searching => write!(f, "Searching..."),
+            Status::Found(addr) => write!(f, "Found at {:06X}", addr),
+            Status::NotFound => write!(f, "Not found"),
+            Status::Matches(1) => write!(f, "1 match"),
+            Status::Matches(count) if *count >= MAX_RESULTS => write!(f, "{}+ matches", count),
+            Status::Matches(count) => write!(f, "{} matches", count),
+            Status::Replaced {
+                replaced,
+                skipped,
+                truncated: true,
+            } => write!(
+                f,
+                "Replaced {}, skipped {}, stopped at {} matches",
+                replaced, skipped, MAX_RESULTS
+            ),
+            Status::Replaced {
+                replaced, skipped, ..
+            } => write!(f, "Replaced {}, skipped {}", replaced, skipped),
+            Status::Scanning => write!(f, "Scanning..."),
+            Status::Candidates {
+                count,
+                truncated: true,
+            } => write!(f, "{}+ candidates", count),
+            Status::Candidates { count: 1, .. } => write!(f, "1 candidate"),
+            Status::Candidates { count, .. } => write!(f, "{} candidates", count),
+            Status::InvalidValue => write!(f, "Invalid value"),
+            Status::OutOfRange => write!(f, "Out of range"),
+            Status::NotWritable => write!(f, "Not writable"),
+            Status::InvalidHex => write!(f, "Invalid hex"),
+            Status::CopyTooLarge => write!(f, "Selection too large to copy"),
+        }
+    }
+}
+
+pub(crate) enum Progress {
+    Found(Range<usize>),
+    Pending,
+    NotFound,
+}
+
+/// A search that wraps around the address range once, scanning a bounded
+/// number of chunks on each step.
+pub(crate) struct Search {
+    pattern: Pattern,
+    direction: Direction,
+    range: Range<usize>,
+    alignment: usize,
+    position: usize,
+    remaining: usize,
+}
+
+impl Search {
+    /// Forward searches start at `from`, backward searches at the address before it.
+    pub(crate) fn new(
+        pattern: Pattern,
+        direction: Direction,
+        range: Range<usize>,
+        from: usize,
+    ) -> Self {
+        let position = match direction {
+            Direction::Forward if range.contains(&from) => from,
+            Direction::Forward => range.start,
+            Direction::Backward if from > range.start && from <= range.end => from - 1,
+            Direction::Backward => range.end.saturating_sub(1),
+        };
+
+        Self {
+            alignment: pattern.alignment(),
+            pattern,
+            direction,
+            remaining: range.len(),
+            range,
+            position,
+        }
+    }
+
+    pub(crate) fn step(&mut self, read: impl Fn(Range<usize>) -> Vec<Option<u8>>) -> Progress {
+        let len = self.pattern.len();
+        if len == 0 {
+            return Progress::NotFound;
+        }
+
+        for _ in 0..CHUNKS_PER_STEP {
+            if self.remaining == 0 {
+                return Progress::NotFound;
+            }
+
+            match self.direction {
+                Direction::Forward => {
+                    let end = (self.position + CHUNK_SIZE.min(self.remaining)).min(self.range.end);
+                    let data = read(self.position..(end + len - 1).min(self.range.end));
+
+                    if let Some(found) = (self.position..end)
+                        .find(|&addr| self.matches_at(&data, self.position, addr))
+                    {
+                        return Progress::Found(found..found + len);
+                    }
+
+                    self.remaining -= end - self.position;
+                    self.position = if end == self.range.end {
+                        self.range.start
+                    } else {
+                        end
+                    };
+                }
+                Direction::Backward => {
+                    let start = (self.position + 1)
+                        .saturating_sub(CHUNK_SIZE.min(self.remaining))
+                        .max(self.range.start);
+                    let data = read(start..(self.position + len).min(self.range.end));
+
+                    if let Some(found) = (start..=self.position)
+                        .rev()
+                        .find(|&addr| self.matches_at(&data, start, addr))
+                    {
+                        return Progress::Found(found..found + len);
+                    }
+
+                    self.remaining -= self.position + 1 - start;
+                    self.position = if start == self.range.start {
+                        self.range.end.saturating_sub(1)
+                    } else {
+                        start - 1
+                    };
+                }
+            }
+        }
+
+        Progress::Pending
+    }
+
+    /// Collects the start of every match in the next chunks, ignoring the
+    /// search direction and wrapping around like `step`. Returns true once the
+    /// range has been scanned, with the results in address order.
+    pub(crate) fn find_all(
+        &mut self,
+        read: impl Fn(Range<usize>) -> Vec<Option<u8>>,
+        results: &mut Vec<usize>,
+    ) -> bool {
+        let len = self.pattern.len();
+
+        for _ in 0..CHUNKS_PER_STEP {
+            if self.remaining == 0 || results.len() >= MAX_RESULTS {
+                results.truncate(MAX_RESULTS);
+                results.sort_unstable();
+                return true;
+            }
+
+            let end = (self.position + CHUNK_SIZE.min(self.remaining)).min(self.range.end);
+            let data = read(self.position..(end + len - 1).min(self.range.end));
+
+            results.extend(
+                (self.position..end).filter(|&addr| self.matches_at(&data, self.position, addr)),
+            );
+
+            self.remaining -= end - self.position;
+            self.position = if end == self.range.end {
+                self.range.start
+            } else {
+                end
+            };
+        }
+
+        false
+    }
+
+    fn matches_at(&self, data: &[Option<u8>], start: usize, addr: usize) -> bool {
+        addr.is_multiple_of(self.alignment)
+            && data
+                .get(addr - start..)
+                .is_some_and(|data| self.pattern.matches(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memory(len: usize, patches: &[(usize, &[u8])]) -> Vec<Option<u8>> {
+        let mut data = vec![Some(0); len];
+        for (addr, bytes) in patches {
+            for (offset, byte) in bytes.iter().enumerate() {
+                data[addr + offset] = Some(*byte);
+            }
+        }
+        data
+    }
+
+    fn run(search: &mut Search, data: &[Option<u8>]) -> Option<usize> {
+        loop {
+            match search.step(|range| data[range].to_vec()) {
+                Progress::Found(found) => return Some(found.start),
+                Progress::NotFound => return None,
+                Progress::Pending => {}
+            }
+        }
+    }
+
+    fn find(
+        pattern: &Pattern,
+        direction: Direction,
+        data: &[Option<u8>],
+        from: usize,
+    ) -> Option<usize> {
+        let mut search = Search::new(pattern.clone(), direction, 0..data.len(), from);
+        run(&mut search, data)
+    }
+
+    #[test]
+    fn parse_hex() {
+        assert_eq!(
+            Pattern::parse_hex("A9 ?? 8d00 ?"),
+            Some(Pattern::Bytes(vec![
+                Some(0xA9),
+                None,
+                Some(0x8D),
+                Some(0x00),
+                None
+            ]))
+        );
+        assert!(Pattern::parse_hex("A").is_none());
+        assert!(Pattern::parse_hex("GG").is_none());
+        assert!(Pattern::parse_hex("  ").is_none());
+    }
+
+    #[test]
+    fn wildcards_skip_unreadable_bytes() {
+        let pattern = Pattern::parse_hex("01 ?? 03").unwrap();
+        assert!(pattern.matches(&[Some(1), Some(2), Some(3)]));
+        assert!(!pattern.matches(&[Some(1), None, Some(3)]));
+        assert!(!pattern.matches(&[Some(1), Some(2)]));
+    }
+
+    #[test]
+    fn text() {
+        let pattern = Pattern::parse_text("HeLLo", TextEncoding::Ascii, false).unwrap();
+        assert!(pattern.matches(&b"hello".map(Some)));
+        let pattern = Pattern::parse_text("HeLLo", TextEncoding::Ascii, true).unwrap();
+        assert!(!pattern.matches(&b"hello".map(Some)));
+
+        let pattern = Pattern::parse_text("hé", TextEncoding::Utf16Le, true).unwrap();
+        assert_eq!(pattern.len(), 4);
+        assert!(pattern.matches(&[Some(b'h'), Some(0), Some(0xE9), Some(0)]));
+        assert!(Pattern::parse_text("é", TextEncoding::Ascii, true).is_none());
+    }
+
+    #[test]
+    fn values() {
+        let pattern =
+            Pattern::parse_value("1.5", PreviewDataFormat::F32, Endianness::Big, 0.01, false)
+                .unwrap();
+        assert!(pattern.matches(&1.505f32.to_be_bytes().map(Some)));
+        assert!(!pattern.matches(&1.6f32.to_be_bytes().map(Some)));
+
+        let pattern = Pattern::parse_value(
+            "300",
+            PreviewDataFormat::Uleb128,
+            Endianness::Little,
+            0.0,
+            false,
+        )
+        .unwrap();
+        assert_eq!(pattern, Pattern::Bytes(vec![Some(0xAC), Some(0x02)]));
+    }
+
+    #[test]
+    fn aligned_values() {
+        let data = memory(16, &[(2, &[0x34, 0x12]), (8, &[0x34, 0x12])]);
+        let pattern = |aligned| {
+            Pattern::parse_value(
+                "0x1234",
+                PreviewDataFormat::U16,
+                Endianness::Little,
+                0.0,
+                aligned,
+            )
+            .unwrap()
+        };
+        assert_eq!(find(&pattern(false), Direction::Forward, &data, 0), Some(2));
+        // U16 is 2 byte aligned
+        let data = memory(16, &[(3, &[0x34, 0x12]), (8, &[0x34, 0x12])]);
+        assert_eq!(find(&pattern(true), Direction::Forward, &data, 0), Some(8));
+    }
+
+    #[test]
+    fn forward_wraps_around() {
+        let data = memory(3 * CHUNK_SIZE, &[(0x100, &[0xAA, 0xBB])]);
+        let pattern = Pattern::parse_hex("AA BB").unwrap();
+
+        assert_eq!(find(&pattern, Direction::Forward, &data, 0), Some(0x100));
+        assert_eq!(
+            find(&pattern, Direction::Forward, &data, 0x101),
+            Some(0x100)
+        );
+        assert_eq!(
+            find(&pattern, Direction::Forward, &data, 2 * CHUNK_SIZE),
+            Some(0x100)
+        );
+    }
+
+    #[test]
+    fn backward_wraps_around() {
+        let data = memory(3 * CHUNK_SIZE, &[(2 * CHUNK_SIZE + 5, &[0xAA, 0xBB])]);
+        let pattern = Pattern::parse_hex("AA BB").unwrap();
+
+        assert_eq!(
+            find(&pattern, Direction::Backward, &data, 3 * CHUNK_SIZE),
+            Some(2 * CHUNK_SIZE + 5)
+        );
+        assert_eq!(
+            find(&pattern, Direction::Backward, &data, 0x10),
+            Some(2 * CHUNK_SIZE + 5)
+        );
+        // The match at the cursor is only found again after a full lap
+        assert_eq!(
+            find(&pattern, Direction::Backward, &data, 2 * CHUNK_SIZE + 5),
+            Some(2 * CHUNK_SIZE + 5)
+        );
+    }
+
+    #[test]
+    fn matches_across_chunks() {
+        let data = memory(2 * CHUNK_SIZE, &[(CHUNK_SIZE - 1, &[0xAA, 0xBB])]);
+        let pattern = Pattern::parse_hex("AA BB").unwrap();
+
+        assert_eq!(
+            find(&pattern, Direction::Forward, &data, 0),
+            Some(CHUNK_SIZE - 1)
+        );
+        assert_eq!(
+            find(&pattern, Direction::Backward, &data, 2 * CHUNK_SIZE),
+            Some(CHUNK_SIZE - 1)
+        );
+    }
+
+    #[test]
+    fn not_found_after_one_lap() {
+        let data = memory(3 * CHUNK_SIZE, &[]);
+        let pattern = Pattern::parse_hex("AA").unwrap();
+
+        assert_eq!(find(&pattern, Direction::Forward, &data, 0x1234), None);
+        assert_eq!(find(&pattern, Direction::Backward, &data, 0x1234), None);
+    }
+
+    #[test]
+    fn offset_range() {
+        let data = memory(0x2000, &[(0x1000, &[0xAA])]);
+        let pattern = Pattern::parse_hex("AA").unwrap();
+        let read = |range: Range<usize>| data[range].to_vec();
+
+        let mut search = Search::new(pattern.clone(), Direction::Forward, 0x1001..0x2000, 0);
+        assert!(matches!(search.step(read), Progress::NotFound));
+        let mut search = Search::new(pattern, Direction::Forward, 0x800..0x2000, 0x1800);
+        assert_eq!(run(&mut search, &data), Some(0x1000));
+    }
+
+    #[test]
+    fn find_all() {
+        let data = memory(
+            2 * CHUNK_SIZE,
+            &[
+                (0, &[0xAA]),
+                (CHUNK_SIZE, &[0xAA]),
+                (2 * CHUNK_SIZE - 1, &[0xAA]),
+            ],
+        );
+        let pattern = Pattern::parse_hex("AA").unwrap();
+        let mut search = Search::new(pattern, Direction::Forward, 0..data.len(), 0);
+        let mut results = Vec::new();
+
+        while !search.find_all(|range| data[range].to_vec(), &mut results) {}
+        assert_eq!(results, vec![0, CHUNK_SIZE, 2 * CHUNK_SIZE - 1]);
+    }
+
+    #[test]
+    fn find_all_from_mid_range() {
+        let data = memory(
+            2 * CHUNK_SIZE,
+            &[(0x10, &[0xAA, 0xBB]), (CHUNK_SIZE + 0x10, &[0xAA, 0xBB])],
+        );
+        let pattern = Pattern::parse_hex("AA BB").unwrap();
+
+        for (direction, from) in [
+            (Direction::Forward, 0x11),
+            (Direction::Forward, CHUNK_SIZE + 0x11),
+            (Direction::Backward, 0x11),
+        ] {
+            let mut search = Search::new(pattern.clone(), direction, 0..data.len(), from);
+            let mut results = Vec::new();
+
+            while !search.find_all(|range| data[range].to_vec(), &mut results) {}
+            assert_eq!(results, vec![0x10, CHUNK_SIZE + 0x10]);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use std::ops::Range;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Selection {
+    pub anchor: usize,
+    pub cursor: usize,
+}
+
+impl Selection {
+    pub fn new(address: usize) -> Self {
+        Self {
+            anchor: address,
+            cursor: address,
+        }
+    }
+
+    pub fn from_range(range: Range<usize>) -> Option<Self> {
+        if range.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            anchor: range.start,
+            cursor: range.end - 1,
+        })
+    }
+
+    pub fn extend(self, cursor: usize) -> Self {
+        Self { cursor, ..self }
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        self.anchor.min(self.cursor)..self.anchor.max(self.cursor) + 1
+    }
+
+    pub fn contains(&self, address: usize) -> bool {
+        self.range().contains(&address)
+    }
+}
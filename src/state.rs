@@ -1,5 +1,11 @@
+use crate::options::{CellRadix, PreviewDataFormat};
+use crate::scan::{ScanFilter, Scanner};
+use crate::search::{Search, SearchMode, Status, TextEncoding};
+use crate::selection::Selection;
+use crate::value::Value;
 #[cfg(feature = "libcosmic")]
 use cosmic::iced_core::{
+    keyboard,
     widget::text::{LineHeight, Shaping, Wrapping},
     Font, Pixels, Rectangle, Size,
     {widget::operation::Focusable, Text},
@@ -7,15 +13,19 @@ use cosmic::iced_core::{
 #[cfg(feature = "iced")]
 use iced_core::{
     alignment::Vertical,
+    keyboard,
     widget::text::{Alignment, LineHeight, Shaping, Wrapping},
     Font, Pixels, Rectangle, Size,
     {widget::operation::Focusable, Text},
 };
+use std::ops::Range;
 
 pub(crate) struct DimensionsState {
     pub(crate) char_height: f32,
     pub(crate) char_width: f32,
+    pub(crate) cell_width: f32,
     pub(crate) byte_width: f32,
+    pub(crate) cell_bytes: usize,
     pub(crate) group_spacing: f32,
     pub(crate) section_separator_spacing: f32,
     pub(crate) section_data_start: f32,
@@ -27,17 +37,61 @@ pub(crate) struct DimensionsState {
     pub(crate) group_char_len: usize,
 }
 
+pub(crate) const OPTIONS_PANEL_LINES: usize = 7;
+pub(crate) const SEARCH_PANEL_LINES: usize = 5;
+pub(crate) const SCAN_PANEL_LINES: usize = 5;
+pub(crate) const INSPECTOR_PANEL_LINES: usize = 9;
+
+#[derive(Default)]
+pub(crate) struct SelectorBounds {
+    pub(crate) prev: Rectangle,
+    pub(crate) text: Rectangle,
+    pub(crate) next: Rectangle,
+}
+
 pub(crate) struct BoundsState {
     pub(crate) options: Rectangle,
+    pub(crate) search_toggle: Rectangle,
+    pub(crate) search_input: Rectangle,
+    pub(crate) search_nav: SelectorBounds,
+    pub(crate) find_all: Rectangle,
+    pub(crate) replace_input: Rectangle,
+    pub(crate) replace: Rectangle,
+    pub(crate) replace_all: Rectangle,
+    pub(crate) results: Rectangle,
+    pub(crate) search_mode: SelectorBounds,
+    pub(crate) text_encoding: SelectorBounds,
+    pub(crate) match_case_checkbox: Rectangle,
+    pub(crate) value_format: SelectorBounds,
+    pub(crate) aligned_checkbox: Rectangle,
+    pub(crate) value_endianness: SelectorBounds,
+    pub(crate) epsilon_input: Rectangle,
+    pub(crate) scan_toggle: Rectangle,
+    pub(crate) inspector_toggle: Rectangle,
+    pub(crate) scan_input: Rectangle,
+    pub(crate) scan_first: Rectangle,
+    pub(crate) scan_next: Rectangle,
+    pub(crate) scan_reset: Rectangle,
+    pub(crate) scan_status: Rectangle,
+    pub(crate) scan_filter: SelectorBounds,
+    pub(crate) scan_list: Rectangle,
     pub(crate) addr_input: Rectangle,
     pub(crate) byte_input: Rectangle,
     pub(crate) show_ascii_checkbox: Rectangle,
-    pub(crate) prev_format: Rectangle,
-    pub(crate) next_format: Rectangle,
-    pub(crate) text_format: Rectangle,
-    pub(crate) prev_row_length: Rectangle,
-    pub(crate) next_row_length: Rectangle,
-    pub(crate) text_row_length: Rectangle,
+    pub(crate) format: SelectorBounds,
+    pub(crate) row_length: SelectorBounds,
+    pub(crate) cell_radix: SelectorBounds,
+    pub(crate) cell_size: SelectorBounds,
+    pub(crate) copy_format: SelectorBounds,
+    pub(crate) endianness: SelectorBounds,
+    pub(crate) float_format: SelectorBounds,
+    pub(crate) float_precision: SelectorBounds,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Section {
+    Hex,
+    Ascii,
 }
 
 pub(crate) struct InputState {
@@ -56,9 +110,43 @@ pub(crate) struct State {
     pub(crate) focused: bool,
     pub(crate) text_defaults: Text,
     pub(crate) options_open: bool,
+    pub(crate) search_open: bool,
+    pub(crate) search_input: InputState,
+    pub(crate) search: Option<Search>,
+    pub(crate) search_status: Status,
+    pub(crate) edit_status: Status,
+    pub(crate) finding_all: bool,
+    pub(crate) search_results: Vec<usize>,
+    pub(crate) search_match_len: usize,
+    pub(crate) results_scroll: usize,
+    pub(crate) replace_input: InputState,
+    pub(crate) pending_replacement: Option<Vec<Option<u8>>>,
+    pub(crate) search_mode: SearchMode,
+    pub(crate) text_encoding: TextEncoding,
+    pub(crate) match_case: bool,
+    pub(crate) value_format: PreviewDataFormat,
+    pub(crate) aligned: bool,
+    pub(crate) epsilon_input: InputState,
+    pub(crate) scan_open: bool,
+    pub(crate) scan_input: InputState,
+    pub(crate) scanner: Option<Scanner>,
+    pub(crate) scan_status: Status,
+    pub(crate) scan_filter: ScanFilter,
+    pub(crate) scan_scroll: usize,
+    pub(crate) scan_values: Vec<Option<Value>>,
+    pub(crate) inspector_open: bool,
+    pub(crate) inspector_data: Vec<Option<u8>>,
     pub(crate) start_address: usize,
-    pub(crate) selected_address: Option<usize>,
-    pub(crate) data: Vec<u8>,
+    pub(crate) selection: Option<Selection>,
+    pub(crate) dragging: bool,
+    pub(crate) modifiers: keyboard::Modifiers,
+    pub(crate) edit_digits: String,
+    pub(crate) edit_section: Section,
+    pub(crate) data: Vec<Option<u8>>,
+    pub(crate) modified: Vec<bool>,
+    pub(crate) needs_reload: bool,
+    pub(crate) pending_scroll: Option<usize>,
+    pub(crate) pending_selection: Option<Range<usize>>,
     pub(crate) dimensions: DimensionsState,
     pub(crate) addr_input: InputState,
     pub(crate) byte_input: InputState,
@@ -72,7 +160,9 @@ impl Default for State {
             dimensions: DimensionsState {
                 char_height: 0.0,
                 char_width: 0.0,
+                cell_width: 0.0,
                 byte_width: 0.0,
+                cell_bytes: 1,
                 group_spacing: 0.0,
                 section_separator_spacing: 0.0,
                 section_data_start: 0.0,
@@ -84,8 +174,16 @@ impl Default for State {
                 group_char_len: 8,
             },
             start_address: 0,
-            selected_address: None,
+            selection: None,
+            dragging: false,
+            modifiers: keyboard::Modifiers::default(),
+            edit_digits: String::new(),
+            edit_section: Section::Hex,
             data: Vec::new(),
+            modified: Vec::new(),
+            needs_reload: true,
+            pending_scroll: None,
+            pending_selection: None,
             addr_input: InputState {
                 value: String::new(),
                 focused: false,
@@ -113,17 +211,81 @@ impl Default for State {
                 wrapping: Wrapping::None,
             },
             options_open: false,
+            search_open: false,
+            search_input: InputState {
+                value: String::new(),
+                focused: false,
+            },
+            search: None,
+            search_status: Status::Idle,
+            edit_status: Status::Idle,
+            finding_all: false,
+            search_results: Vec::new(),
+            search_match_len: 0,
+            results_scroll: 0,
+            replace_input: InputState {
+                value: String::new(),
+                focused: false,
+            },
+            pending_replacement: None,
+            search_mode: SearchMode::default(),
+            text_encoding: TextEncoding::default(),
+            match_case: false,
+            value_format: PreviewDataFormat::default(),
+            aligned: true,
+            epsilon_input: InputState {
+                value: "0.0001".to_string(),
+                focused: false,
+            },
+            scan_open: false,
+            scan_input: InputState {
+                value: String::new(),
+                focused: false,
+            },
+            scanner: None,
+            scan_status: Status::Idle,
+            scan_filter: ScanFilter::default(),
+            scan_scroll: 0,
+            scan_values: Vec::new(),
+            inspector_open: false,
+            inspector_data: Vec::new(),
             bounds: BoundsState {
                 options: Rectangle::default(),
+                search_toggle: Rectangle::default(),
+                search_input: Rectangle::default(),
+                search_nav: SelectorBounds::default(),
+                find_all: Rectangle::default(),
+                replace_input: Rectangle::default(),
+                replace: Rectangle::default(),
+                replace_all: Rectangle::default(),
+                results: Rectangle::default(),
+                search_mode: SelectorBounds::default(),
+                text_encoding: SelectorBounds::default(),
+                match_case_checkbox: Rectangle::default(),
+                value_format: SelectorBounds::default(),
+                aligned_checkbox: Rectangle::default(),
+                value_endianness: SelectorBounds::default(),
+                epsilon_input: Rectangle::default(),
+                scan_toggle: Rectangle::default(),
+                inspector_toggle: Rectangle::default(),
+                scan_input: Rectangle::default(),
+                scan_first: Rectangle::default(),
+                scan_next: Rectangle::default(),
+                scan_reset: Rectangle::default(),
+                scan_status: Rectangle::default(),
+                scan_filter: SelectorBounds::default(),
+                scan_list: Rectangle::default(),
                 addr_input: Rectangle::default(),
                 byte_input: Rectangle::default(),
                 show_ascii_checkbox: Rectangle::default(),
-                prev_format: Rectangle::default(),
-                next_format: Rectangle::default(),
-                text_format: Rectangle::default(),
-                prev_row_length: Rectangle::default(),
-                next_row_length: Rectangle::default(),
-                text_row_length: Rectangle::default(),
+                format: SelectorBounds::default(),
+                row_length: SelectorBounds::default(),
+                cell_radix: SelectorBounds::default(),
+                cell_size: SelectorBounds::default(),
+                copy_format: SelectorBounds::default(),
+                endianness: SelectorBounds::default(),
+                float_format: SelectorBounds::default(),
+                float_precision: SelectorBounds::default(),
             },
             text: TextState {
                 jumpto_text: "Jump to".to_string(),
@@ -136,8 +298,71 @@ impl Default for State {
 }
 
 impl State {
-    pub(crate) fn update_dimensions(&mut self, row_length: f32) {
-        self.dimensions.byte_width = self.dimensions.char_width * 2.5;
+    /// Moves the selection, dropping any partially typed cell.
+    pub(crate) fn set_selection(&mut self, selection: Option<Selection>) {
+        self.selection = selection;
+        self.edit_digits.clear();
+        self.edit_status = Status::Idle;
+        match selection {
+            Some(selection) => {
+                self.text.value_text = format!("{:06X} =", selection.cursor);
+                self.text.value_len =
+                    self.text.value_text.len() as f32 * self.dimensions.char_width;
+            }
+            None => self.text.value_text.clear(),
+        }
+    }
+
+    pub(crate) fn cursor_address(&self) -> Option<usize> {
+        self.selection.map(|selection| selection.cursor)
+    }
+
+    pub(crate) fn results_visible(&self) -> bool {
+        self.search_open && !self.search_results.is_empty()
+    }
+
+    pub(crate) fn scan_visible(&self) -> bool {
+        self.scan_open
+            && self
+                .scanner
+                .as_ref()
+                .is_some_and(|scanner| !scanner.is_empty())
+    }
+
+    pub(crate) fn is_match(&self, addr: usize) -> bool {
+        let idx = self
+            .search_results
+            .partition_point(|&start| start + self.search_match_len <= addr);
+        self.search_results
+            .get(idx)
+            .is_some_and(|&start| start <= addr)
+    }
+
+    pub(crate) fn focused_search_input(&mut self) -> Option<&mut InputState> {
+        [
+            &mut self.search_input,
+            &mut self.replace_input,
+            &mut self.epsilon_input,
+            &mut self.scan_input,
+        ]
+        .into_iter()
+        .find(|input| input.focused)
+    }
+
+    pub(crate) fn update_dimensions(
+        &mut self,
+        row_length: f32,
+        cell_radix: CellRadix,
+        cell_bytes: usize,
+    ) {
+        // Words cut short are drawn byte by byte with the same gap as single byte cells,
+        // the cell is widened when those bytes don't fit in it
+        let byte_chars = cell_radix.digits(1) as f32 + 0.5;
+        let partial_chars = (cell_bytes - 1) as f32 * byte_chars - 0.5;
+        self.dimensions.cell_width = self.dimensions.char_width
+            * ((cell_radix.digits(cell_bytes) as f32).max(partial_chars) + 0.5);
+        self.dimensions.byte_width = self.dimensions.char_width * byte_chars;
+        self.dimensions.cell_bytes = cell_bytes;
         self.dimensions.group_spacing = self.dimensions.char_width;
         self.dimensions.section_separator_spacing = self.dimensions.char_width * 2.0;
 
@@ -145,7 +370,7 @@ impl State {
             * self.dimensions.address_char_len as f32
             + self.dimensions.section_separator_spacing;
         self.dimensions.section_ascii_start = self.dimensions.section_data_start
-            + self.dimensions.byte_width * row_length
+            + self.dimensions.cell_width * (row_length / cell_bytes as f32).ceil()
             + (row_length / self.dimensions.group_char_len as f32 - 1.0)
                 * self.dimensions.group_spacing
             + self.dimensions.section_separator_spacing;
@@ -168,14 +393,45 @@ impl State {
             height: self.dimensions.char_height * 1.5,
         };
 
+        let search_text = "Search";
+        let search_width = search_text.len() as f32 * self.dimensions.char_width;
+        self.bounds.search_toggle = Rectangle {
+            x: bounds.x + options_width + self.dimensions.char_width,
+            width: search_width,
+            ..self.bounds.options
+        };
+
+        let scan_text = "Scan";
+        let scan_width = scan_text.len() as f32 * self.dimensions.char_width;
+        self.bounds.scan_toggle = Rectangle {
+            x: self.bounds.search_toggle.x + search_width + self.dimensions.char_width,
+            width: scan_width,
+            ..self.bounds.options
+        };
+
+        let inspector_text = "Inspect";
+        let inspector_width = inspector_text.len() as f32 * self.dimensions.char_width;
+        self.bounds.inspector_toggle = Rectangle {
+            x: self.bounds.scan_toggle.x + scan_width + self.dimensions.char_width,
+            width: inspector_width,
+            ..self.bounds.options
+        };
+
+        let buttons_width = options_width
+            + self.dimensions.char_width
+            + search_width
+            + self.dimensions.char_width
+            + scan_width
+            + self.dimensions.char_width
+            + inspector_width;
         let total_width = bounds.width;
-        let available_width = total_width - options_width;
+        let available_width = total_width - buttons_width;
         let input_width =
             (self.dimensions.char_width + 1.0) * self.dimensions.address_char_len as f32 * 1.1;
-        let byte_input_width = (self.dimensions.char_width + 1.0) * 4.0 * 1.1;
+        let byte_input_width = (self.dimensions.char_width + 1.0) * 12.0 * 1.1;
         let spacing = (available_width - input_width - byte_input_width) / 3.0;
 
-        let jumpto_x = bounds.x + spacing;
+        let jumpto_x = bounds.x + buttons_width + spacing;
         let input_x = jumpto_x + self.text.jumpto_len + self.dimensions.char_width;
 
         self.bounds.addr_input = Rectangle {
@@ -193,75 +449,255 @@ impl State {
             height: self.dimensions.char_height * 1.1,
         };
 
-        if let Some(addr) = self.selected_address {
+        if let Some(addr) = self.cursor_address() {
             self.text.value_text = format!("{:06X} =", addr);
         } else {
             self.text.value_text = String::new();
         }
         self.text.value_len = self.text.value_text.len() as f32 * self.dimensions.char_width;
 
-        let panel_bounds = Rectangle {
-            x: bounds.x + self.dimensions.char_width * 0.5,
-            y: bounds.y + bounds.height
-                - self.dimensions.char_height * 1.5
-                - self.dimensions.char_height * 4.0,
-            width: bounds.width - self.dimensions.char_width,
-            height: self.dimensions.char_height * 4.0,
-        };
-
         let label_width = 120.0;
-        let offset_y = panel_bounds.y + self.dimensions.char_height * 0.5;
         let checkbox_size = self.dimensions.char_height * 0.8;
-        let base_x = panel_bounds.x + self.dimensions.char_width + label_width;
 
-        self.bounds.show_ascii_checkbox = Rectangle {
-            x: base_x + 3.0 * self.dimensions.char_width,
-            y: offset_y + self.dimensions.char_height * 2.0,
-            width: checkbox_size,
-            height: checkbox_size,
+        let search_panel = self.search_panel_bounds(bounds);
+        let search_y = search_panel.y + self.dimensions.char_height * 0.5;
+        let search_x = search_panel.x + self.dimensions.char_width * 1.5 + label_width;
+        let search_width = (self.dimensions.char_width * 32.0).min(
+            search_panel.x + search_panel.width - search_x - self.dimensions.char_width * 24.0,
+        );
+
+        self.bounds.search_input = Rectangle {
+            x: search_x,
+            y: search_y,
+            width: search_width.max(self.dimensions.char_width * 8.0),
+            height: self.dimensions.char_height,
         };
 
-        self.bounds.text_format = Rectangle {
-            x: base_x + 2.0 * self.dimensions.char_width,
-            y: offset_y + self.dimensions.char_height,
+        let nav_x = self.bounds.search_input.x
+            + self.bounds.search_input.width
+            + self.dimensions.char_width;
+        self.bounds.search_nav = SelectorBounds {
+            prev: Rectangle {
+                x: nav_x,
+                y: search_y,
+                width: self.dimensions.char_width,
+                height: self.dimensions.char_height,
+            },
+            next: Rectangle {
+                x: nav_x + self.dimensions.char_width * 2.0,
+                y: search_y,
+                width: self.dimensions.char_width,
+                height: self.dimensions.char_height,
+            },
+            text: Rectangle {
+                x: nav_x + self.dimensions.char_width * 8.0,
+                y: search_y,
+                width: self.dimensions.char_width * 20.0,
+                height: self.dimensions.char_height,
+            },
+        };
+
+        self.bounds.find_all = Rectangle {
+            x: nav_x + self.dimensions.char_width * 4.0,
+            y: search_y,
             width: self.dimensions.char_width * 3.0,
             height: self.dimensions.char_height,
         };
 
-        self.bounds.prev_format = Rectangle {
-            x: base_x,
-            y: offset_y + self.dimensions.char_height,
-            width: self.dimensions.char_width,
-            height: self.dimensions.char_height,
+        let results_width = self.dimensions.char_width * 10.0;
+        self.bounds.results = Rectangle {
+            x: bounds.x + bounds.width - results_width,
+            y: bounds.y,
+            width: results_width,
+            height: self.dimensions.char_height * self.dimensions.row_count as f32,
         };
 
-        self.bounds.next_format = Rectangle {
-            x: base_x + 6.0 * self.dimensions.char_width,
-            y: offset_y + self.dimensions.char_height,
-            width: self.dimensions.char_width,
+        self.bounds.search_mode =
+            self.selector_bounds(search_x, search_y + self.dimensions.char_height);
+        self.bounds.replace_input = Rectangle {
+            x: self.bounds.search_mode.next.x + self.dimensions.char_width * 8.0,
+            y: self.bounds.search_mode.next.y,
+            width: self.bounds.search_input.width,
             height: self.dimensions.char_height,
         };
+        self.bounds.replace = Rectangle {
+            x: self.bounds.replace_input.x
+                + self.bounds.replace_input.width
+                + self.dimensions.char_width,
+            width: self.dimensions.char_width * 7.0,
+            ..self.bounds.replace_input
+        };
+        self.bounds.replace_all = Rectangle {
+            x: self.bounds.replace.x + self.bounds.replace.width + self.dimensions.char_width,
+            width: self.dimensions.char_width * 3.0,
+            ..self.bounds.replace_input
+        };
 
-        self.bounds.prev_row_length = Rectangle {
-            x: base_x,
-            y: offset_y,
-            width: self.dimensions.char_width,
-            height: self.dimensions.char_height,
+        self.bounds.text_encoding =
+            self.selector_bounds(search_x, search_y + self.dimensions.char_height * 2.0);
+
+        self.bounds.match_case_checkbox = Rectangle {
+            x: self.bounds.text_encoding.next.x + self.dimensions.char_width * 14.0,
+            y: self.bounds.text_encoding.next.y,
+            width: checkbox_size,
+            height: checkbox_size,
         };
 
-        self.bounds.next_row_length = Rectangle {
-            x: base_x + 6.0 * self.dimensions.char_width,
-            y: offset_y,
-            width: self.dimensions.char_width,
+        self.bounds.value_format =
+            self.selector_bounds(search_x, search_y + self.dimensions.char_height * 2.0);
+        self.bounds.aligned_checkbox = self.bounds.match_case_checkbox;
+        self.bounds.value_endianness =
+            self.selector_bounds(search_x, search_y + self.dimensions.char_height * 3.0);
+        self.bounds.epsilon_input = Rectangle {
+            x: self.bounds.value_endianness.next.x + self.dimensions.char_width * 11.0,
+            y: self.bounds.value_endianness.next.y,
+            width: self.dimensions.char_width * 12.0,
             height: self.dimensions.char_height,
         };
 
-        self.bounds.text_row_length = Rectangle {
-            x: base_x + 2.0 * self.dimensions.char_width,
-            y: offset_y,
-            width: self.dimensions.char_width * 3.0,
-            height: self.dimensions.char_height,
+        self.bounds.scan_input = self.bounds.search_input;
+        for (button, offset, len) in [
+            (&mut self.bounds.scan_first, 0.0, 5.0),
+            (&mut self.bounds.scan_next, 6.0, 4.0),
+            (&mut self.bounds.scan_reset, 11.0, 5.0),
+            (&mut self.bounds.scan_status, 17.0, 20.0),
+        ] {
+            *button = Rectangle {
+                x: nav_x + self.dimensions.char_width * offset,
+                y: search_y,
+                width: self.dimensions.char_width * len,
+                height: self.dimensions.char_height,
+            };
+        }
+        self.bounds.scan_filter =
+            self.selector_bounds(search_x, search_y + self.dimensions.char_height);
+
+        let scan_list_width = self.dimensions.char_width * 28.0;
+        self.bounds.scan_list = Rectangle {
+            x: bounds.x + bounds.width - scan_list_width,
+            width: scan_list_width,
+            ..self.bounds.results
+        };
+
+        let options_panel = self.options_panel_bounds(bounds);
+        let panel_bounds = Rectangle {
+            x: options_panel.x + self.dimensions.char_width * 0.5,
+            width: options_panel.width - self.dimensions.char_width,
+            ..options_panel
         };
+
+        let offset_y = panel_bounds.y + self.dimensions.char_height * 0.5;
+        let base_x = panel_bounds.x + self.dimensions.char_width + label_width;
+
+        self.bounds.show_ascii_checkbox = Rectangle {
+            x: base_x + 3.0 * self.dimensions.char_width,
+            y: offset_y + self.dimensions.char_height * 2.0,
+            width: checkbox_size,
+            height: checkbox_size,
+        };
+
+        self.bounds.row_length = self.selector_bounds(base_x, offset_y);
+        self.bounds.cell_radix = self.selector_bounds(
+            self.bounds.row_length.next.x + self.dimensions.char_width * 14.0,
+            offset_y,
+        );
+        self.bounds.format = self.selector_bounds(base_x, offset_y + self.dimensions.char_height);
+        self.bounds.copy_format =
+            self.selector_bounds(base_x, offset_y + self.dimensions.char_height * 3.0);
+        self.bounds.endianness =
+            self.selector_bounds(base_x, offset_y + self.dimensions.char_height * 4.0);
+        self.bounds.cell_size = self.selector_bounds(
+            self.bounds.endianness.next.x + self.dimensions.char_width * 14.0,
+            self.bounds.endianness.next.y,
+        );
+        self.bounds.float_format =
+            self.selector_bounds(base_x, offset_y + self.dimensions.char_height * 5.0);
+        self.bounds.float_precision = self.selector_bounds(
+            self.bounds.float_format.next.x + self.dimensions.char_width * 14.0,
+            self.bounds.float_format.next.y,
+        );
+    }
+
+    pub(crate) fn search_panel_bounds(&self, bounds: &Rectangle) -> Rectangle {
+        self.tool_panel_bounds(bounds, SEARCH_PANEL_LINES)
+    }
+
+    pub(crate) fn scan_panel_bounds(&self, bounds: &Rectangle) -> Rectangle {
+        self.tool_panel_bounds(bounds, SCAN_PANEL_LINES)
+    }
+
+    /// Lines taken by the search or scan panel, only one of them is open at a time.
+    pub(crate) fn tool_panel_lines(&self) -> usize {
+        if self.search_open {
+            SEARCH_PANEL_LINES
+        } else if self.scan_open {
+            SCAN_PANEL_LINES
+        } else {
+            0
+        }
+    }
+
+    pub(crate) fn inspector_panel_lines(&self) -> usize {
+        if self.inspector_open {
+            INSPECTOR_PANEL_LINES
+        } else {
+            0
+        }
+    }
+
+    pub(crate) fn inspector_panel_bounds(&self, bounds: &Rectangle) -> Rectangle {
+        let height = self.dimensions.char_height * INSPECTOR_PANEL_LINES as f32;
+        Rectangle {
+            y: bounds.y + bounds.height
+                - self.dimensions.char_height * (1.5 + self.tool_panel_lines() as f32)
+                - height,
+            height,
+            ..*bounds
+        }
+    }
+
+    fn tool_panel_bounds(&self, bounds: &Rectangle, lines: usize) -> Rectangle {
+        let height = self.dimensions.char_height * lines as f32;
+        Rectangle {
+            y: bounds.y + bounds.height - self.dimensions.char_height * 1.5 - height,
+            height,
+            ..*bounds
+        }
+    }
+
+    pub(crate) fn options_panel_bounds(&self, bounds: &Rectangle) -> Rectangle {
+        let below = self.tool_panel_lines() + self.inspector_panel_lines();
+        let height = self.dimensions.char_height * OPTIONS_PANEL_LINES as f32;
+        Rectangle {
+            y: bounds.y + bounds.height
+                - self.dimensions.char_height * (1.5 + below as f32)
+                - height,
+            height,
+            ..*bounds
+        }
+    }
+
+    fn selector_bounds(&self, x: f32, y: f32) -> SelectorBounds {
+        SelectorBounds {
+            prev: Rectangle {
+                x,
+                y,
+                width: self.dimensions.char_width,
+                height: self.dimensions.char_height,
+            },
+            text: Rectangle {
+                x: x + 2.0 * self.dimensions.char_width,
+                y,
+                width: self.dimensions.char_width * 8.0,
+                height: self.dimensions.char_height,
+            },
+            next: Rectangle {
+                x: x + 11.0 * self.dimensions.char_width,
+                y,
+                width: self.dimensions.char_width,
+                height: self.dimensions.char_height,
+            },
+        }
     }
 }
 
@@ -1,7 +1,7 @@
-#[cfg(feature = "iced")]
-use iced_core::{Border, Color, Shadow, Theme};
 #[cfg(feature = "libcosmic")]
 use cosmic::iced_core::{Border, Color, Shadow, Theme};
+#[cfg(feature = "iced")]
+use iced_core::{Border, Color, Shadow, Theme};
 
 #[derive(Debug, Clone, Copy)]
 pub struct Style {
@@ -13,6 +13,8 @@ pub struct Style {
     pub shadow: Shadow,
     pub selection_color: Color,
     pub selected_text_color: Color,
+    pub modified_text_color: Color,
+    pub match_color: Color,
 }
 
 pub trait Catalog {
@@ -46,6 +48,8 @@ pub fn default(theme: &Theme) -> Style {
         inactive_text_color: ext.secondary.weak.color,
         selection_color: ext.primary.base.color,
         selected_text_color: ext.primary.base.text,
+        modified_text_color: ext.danger.base.color,
+        match_color: ext.success.weak.color,
         border: Border::default(),
         shadow: Shadow::default(),
     }
@@ -61,6 +65,8 @@ impl From<&cosmic::Theme> for Style {
             inactive_text_color: cosmic.button_bg_color().into(),
             selection_color: cosmic.accent_color().into(),
             selected_text_color: cosmic.primary_container_color().into(),
+            modified_text_color: cosmic.warning_color().into(),
+            match_color: cosmic.success_color().into(),
             border: Border::default(),
             shadow: Shadow::default(),
         }
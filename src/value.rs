@@ -0,0 +1,641 @@
+use crate::options::{Endianness, FloatFormat, PreviewDataFormat};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Value {
+    Unsigned(u128),
+    Signed(i128),
+    Float(f64),
+}
+
+impl Value {
+    pub(crate) fn approx_eq(&self, other: &Value, epsilon: f64) -> bool {
+        match (self, other) {
+            (Value::Float(a), Value::Float(b)) => a == b || (a - b).abs() <= epsilon,
+            _ => self == other,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Unsigned(value) => write!(f, "{}", value),
+            Value::Signed(value) => write!(f, "{}", value),
+            Value::Float(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+fn little_endian<const N: usize>(data: &[u8], endianness: Endianness) -> Option<[u8; N]> {
+    let mut bytes: [u8; N] = data.get(..N)?.try_into().ok()?;
+    if !endianness.is_little() {
+        bytes.reverse();
+    }
+    Some(bytes)
+}
+
+pub fn decode(data: &[u8], format: PreviewDataFormat, endianness: Endianness) -> Option<Value> {
+    Some(match format {
+        PreviewDataFormat::U8 => {
+            Value::Unsigned(u8::from_le_bytes(little_endian(data, endianness)?).into())
+        }
+        PreviewDataFormat::U16 | PreviewDataFormat::Bitfield => {
+            Value::Unsigned(u16::from_le_bytes(little_endian(data, endianness)?).into())
+        }
+        PreviewDataFormat::U32 => {
+            Value::Unsigned(u32::from_le_bytes(little_endian(data, endianness)?).into())
+        }
+        PreviewDataFormat::U64 => {
+            Value::Unsigned(u64::from_le_bytes(little_endian(data, endianness)?).into())
+        }
+        PreviewDataFormat::U128 => {
+            Value::Unsigned(u128::from_le_bytes(little_endian(data, endianness)?))
+        }
+        PreviewDataFormat::I8 => {
+            Value::Signed(i8::from_le_bytes(little_endian(data, endianness)?).into())
+        }
+        PreviewDataFormat::I16 => {
+            Value::Signed(i16::from_le_bytes(little_endian(data, endianness)?).into())
+        }
+        PreviewDataFormat::I32 => {
+            Value::Signed(i32::from_le_bytes(little_endian(data, endianness)?).into())
+        }
+        PreviewDataFormat::I64 => {
+            Value::Signed(i64::from_le_bytes(little_endian(data, endianness)?).into())
+        }
+        PreviewDataFormat::I128 => {
+            Value::Signed(i128::from_le_bytes(little_endian(data, endianness)?))
+        }
+        PreviewDataFormat::F16 => Value::Float(f16_to_f64(u16::from_le_bytes(little_endian(
+            data, endianness,
+        )?))),
+        PreviewDataFormat::BF16 => Value::Float(bf16_to_f64(u16::from_le_bytes(little_endian(
+            data, endianness,
+        )?))),
+        PreviewDataFormat::F32 => {
+            Value::Float(f32::from_le_bytes(little_endian(data, endianness)?).into())
+        }
+        PreviewDataFormat::F64 => {
+            Value::Float(f64::from_le_bytes(little_endian(data, endianness)?))
+        }
+        PreviewDataFormat::Uleb128 => Value::Unsigned(decode_uleb128(data)?.into()),
+        PreviewDataFormat::Sleb128 => Value::Signed(decode_sleb128(data)?.into()),
+        PreviewDataFormat::Bcd => {
+            Value::Unsigned(decode_bcd(little_endian::<4>(data, endianness)?)?.into())
+        }
+    })
+}
+
+/// Parses decimal, or `0x` prefixed hex, integers and decimal floats.
+/// Bitfields are parsed as binary, optionally `0b` prefixed.
+pub fn parse(text: &str, format: PreviewDataFormat) -> Option<Value> {
+    let text = text.trim();
+    match format {
+        PreviewDataFormat::U8 => parse_unsigned(text, u8::MAX.into()),
+        PreviewDataFormat::U16 => parse_unsigned(text, u16::MAX.into()),
+        PreviewDataFormat::U32 => parse_unsigned(text, u32::MAX.into()),
+        PreviewDataFormat::U64 | PreviewDataFormat::Uleb128 => {
+            parse_unsigned(text, u64::MAX.into())
+        }
+        PreviewDataFormat::U128 => parse_unsigned(text, u128::MAX),
+        PreviewDataFormat::I8 => parse_signed(text, i8::MIN.into(), i8::MAX.into()),
+        PreviewDataFormat::I16 => parse_signed(text, i16::MIN.into(), i16::MAX.into()),
+        PreviewDataFormat::I32 => parse_signed(text, i32::MIN.into(), i32::MAX.into()),
+        PreviewDataFormat::I64 | PreviewDataFormat::Sleb128 => {
+            parse_signed(text, i64::MIN.into(), i64::MAX.into())
+        }
+        PreviewDataFormat::I128 => parse_signed(text, i128::MIN, i128::MAX),
+        PreviewDataFormat::F16 => text
+            .parse::<f64>()
+            .ok()
+            .map(|v| Value::Float(f16_to_f64(f16_from_f64(v)))),
+        PreviewDataFormat::BF16 => text
+            .parse::<f64>()
+            .ok()
+            .map(|v| Value::Float(bf16_to_f64(bf16_from_f64(v)))),
+        PreviewDataFormat::F32 => text.parse::<f32>().ok().map(|v| Value::Float(v.into())),
+        PreviewDataFormat::F64 => text.parse::<f64>().ok().map(Value::Float),
+        PreviewDataFormat::Bcd => parse_unsigned(text, BCD_MAX.into()),
+        PreviewDataFormat::Bitfield => {
+            let bits: String = text.chars().filter(|c| !matches!(c, ' ' | '_')).collect();
+            let bits = bits.strip_prefix("0b").unwrap_or(&bits);
+            let value = u16::from_str_radix(bits, 2).ok()?;
+            Some(Value::Unsigned(value.into()))
+        }
+    }
+}
+
+fn parse_magnitude(text: &str) -> Option<u128> {
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u128::from_str_radix(hex, 16).ok(),
+        None => text.parse().ok(),
+    }
+}
+
+fn parse_unsigned(text: &str, max: u128) -> Option<Value> {
+    let value = parse_magnitude(text)?;
+    (value <= max).then_some(Value::Unsigned(value))
+}
+
+fn parse_signed(text: &str, min: i128, max: i128) -> Option<Value> {
+    let value = match text.strip_prefix('-') {
+        Some(text) => 0i128.checked_sub_unsigned(parse_magnitude(text)?)?,
+        None => i128::try_from(parse_magnitude(text)?).ok()?,
+    };
+    (min..=max).contains(&value).then_some(Value::Signed(value))
+}
+
+pub fn encode(value: Value, format: PreviewDataFormat, endianness: Endianness) -> Option<Vec<u8>> {
+    let mut bytes = match (format, value) {
+        (PreviewDataFormat::U8, Value::Unsigned(v)) => u8::try_from(v).ok()?.to_le_bytes().to_vec(),
+        (PreviewDataFormat::U16 | PreviewDataFormat::Bitfield, Value::Unsigned(v)) => {
+            u16::try_from(v).ok()?.to_le_bytes().to_vec()
+        }
+        (PreviewDataFormat::U32, Value::Unsigned(v)) => {
+            u32::try_from(v).ok()?.to_le_bytes().to_vec()
+        }
+        (PreviewDataFormat::U64, Value::Unsigned(v)) => {
+            u64::try_from(v).ok()?.to_le_bytes().to_vec()
+        }
+        (PreviewDataFormat::U128, Value::Unsigned(v)) => v.to_le_bytes().to_vec(),
+        (PreviewDataFormat::I8, Value::Signed(v)) => i8::try_from(v).ok()?.to_le_bytes().to_vec(),
+        (PreviewDataFormat::I16, Value::Signed(v)) => i16::try_from(v).ok()?.to_le_bytes().to_vec(),
+        (PreviewDataFormat::I32, Value::Signed(v)) => i32::try_from(v).ok()?.to_le_bytes().to_vec(),
+        (PreviewDataFormat::I64, Value::Signed(v)) => i64::try_from(v).ok()?.to_le_bytes().to_vec(),
+        (PreviewDataFormat::I128, Value::Signed(v)) => v.to_le_bytes().to_vec(),
+        (PreviewDataFormat::F16, Value::Float(v)) => f16_from_f64(v).to_le_bytes().to_vec(),
+        (PreviewDataFormat::BF16, Value::Float(v)) => bf16_from_f64(v).to_le_bytes().to_vec(),
+        (PreviewDataFormat::F32, Value::Float(v)) => (v as f32).to_le_bytes().to_vec(),
+        (PreviewDataFormat::F64, Value::Float(v)) => v.to_le_bytes().to_vec(),
+        (PreviewDataFormat::Uleb128, Value::Unsigned(v)) => {
+            return Some(encode_uleb128(u64::try_from(v).ok()?));
+        }
+        (PreviewDataFormat::Sleb128, Value::Signed(v)) => {
+            return Some(encode_sleb128(i64::try_from(v).ok()?));
+        }
+        (PreviewDataFormat::Bcd, Value::Unsigned(v)) => {
+            encode_bcd(u32::try_from(v).ok().filter(|v| *v <= BCD_MAX)?).to_vec()
+        }
+        _ => return None,
+    };
+    if !endianness.is_little() {
+        bytes.reverse();
+    }
+    Some(bytes)
+}
+
+pub(crate) const MAX_FLOAT_PRECISION: usize = 17;
+
+/// Formats the float stored in `data`, `None` if `format` isn't a float.
+pub fn format_float(
+    data: &[u8],
+    format: PreviewDataFormat,
+    endianness: Endianness,
+    float_format: FloatFormat,
+    precision: usize,
+) -> Option<String> {
+    let (bits_format, exponent_bits, mantissa_bits) = match format {
+        PreviewDataFormat::F16 => (PreviewDataFormat::U16, 5, 10),
+        PreviewDataFormat::BF16 => (PreviewDataFormat::U16, 8, 7),
+        PreviewDataFormat::F32 => (PreviewDataFormat::U32, 8, 23),
+        PreviewDataFormat::F64 => (PreviewDataFormat::U64, 11, 52),
+        _ => return None,
+    };
+    let Value::Float(value) = decode(data, format, endianness)? else {
+        return None;
+    };
+
+    Some(match float_format {
+        FloatFormat::Shortest => match format {
+            PreviewDataFormat::F32 => format!("{:?}", value as f32),
+            PreviewDataFormat::F64 => format!("{:?}", value),
+            _ => shortest_round_trip(value, format),
+        },
+        FloatFormat::Scientific => format!("{:.*e}", precision, value),
+        FloatFormat::Fixed => format!("{:.*}", precision, value),
+        FloatFormat::HexFloat => {
+            let Value::Unsigned(bits) = decode(data, bits_format, endianness)? else {
+                return None;
+            };
+            hex_float(bits, exponent_bits, mantissa_bits)
+        }
+    })
+}
+
+/// The shortest decimal parsing back to the same half float.
+fn shortest_round_trip(value: f64, format: PreviewDataFormat) -> String {
+    if !value.is_finite() {
+        return format!("{:?}", value);
+    }
+    (0..MAX_FLOAT_PRECISION)
+        .map(|precision| format!("{:.*e}", precision, value))
+        .find_map(|text| {
+            let short: f64 = text.parse().ok()?;
+            (parse(&text, format)? == Value::Float(value)).then_some(short)
+        })
+        .map_or_else(|| format!("{:?}", value), |short| format!("{:?}", short))
+}
+
+/// C99 style hex float, e.g. `0x1.8p+1`, straight from the raw bits.
+fn hex_float(bits: u128, exponent_bits: u32, mantissa_bits: u32) -> String {
+    let sign = if (bits >> (exponent_bits + mantissa_bits)) & 1 == 1 {
+        "-"
+    } else {
+        ""
+    };
+    let max_exponent = (1 << exponent_bits) - 1;
+    let exponent = (bits >> mantissa_bits) & max_exponent;
+    let mantissa = bits & ((1 << mantissa_bits) - 1);
+
+    if exponent == max_exponent {
+        if mantissa == 0 {
+            return format!("{}Inf", if sign.is_empty() { "+" } else { "-" });
+        }
+        let quiet_bit = 1 << (mantissa_bits - 1);
+        let kind = if mantissa & quiet_bit != 0 {
+            "qNaN"
+        } else {
+            "sNaN"
+        };
+        return format!("{}{} 0x{:X}", sign, kind, mantissa & (quiet_bit - 1));
+    }
+
+    if exponent == 0 && mantissa == 0 {
+        return format!("{}0x0p+0", sign);
+    }
+
+    let bias = (1 << (exponent_bits - 1)) - 1;
+    let (lead, exponent) = if exponent == 0 {
+        (0, 1 - bias)
+    } else {
+        (1, exponent as i32 - bias)
+    };
+    let digits = mantissa_bits.div_ceil(4) as usize;
+    let fraction = format!(
+        "{:0digits$x}",
+        mantissa << (digits as u32 * 4 - mantissa_bits),
+        digits = digits
+    );
+    let fraction = fraction.trim_end_matches('0');
+
+    format!(
+        "{}0x{}{}{}p{:+}",
+        sign,
+        lead,
+        if fraction.is_empty() { "" } else { "." },
+        fraction,
+        exponent
+    )
+}
+
+const BCD_MAX: u32 = 99_999_999;
+
+fn decode_bcd(bytes: [u8; 4]) -> Option<u32> {
+    bytes.iter().rev().try_fold(0, |value, byte| {
+        let (high, low) = (u32::from(byte >> 4), u32::from(byte & 0x0F));
+        (high <= 9 && low <= 9).then_some(value * 100 + high * 10 + low)
+    })
+}
+
+fn encode_bcd(mut value: u32) -> [u8; 4] {
+    let mut bytes = [0; 4];
+    for byte in &mut bytes {
+        let digits = (value % 100) as u8;
+        *byte = ((digits / 10) << 4) | (digits % 10);
+        value /= 100;
+    }
+    bytes
+}
+
+fn decode_uleb128(data: &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+    for (index, byte) in data.iter().take(10).enumerate() {
+        let bits = u64::from(byte & 0x7F);
+        if index == 9 && bits > 1 {
+            return None;
+        }
+        value |= bits << (7 * index);
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+fn decode_sleb128(data: &[u8]) -> Option<i64> {
+    let mut value = 0i64;
+    for (index, byte) in data.iter().take(10).enumerate() {
+        let shift = 7 * index;
+        value |= i64::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            if shift + 7 < 64 && byte & 0x40 != 0 {
+                value |= -1 << (shift + 7);
+            }
+            return Some(value);
+        }
+    }
+    None
+}
+
+fn encode_uleb128(mut value: u64) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return bytes;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+fn encode_sleb128(mut value: i64) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0) {
+            bytes.push(byte);
+            return bytes;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+fn f16_to_f64(bits: u16) -> f64 {
+    let sign = if bits & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = i32::from((bits >> 10) & 0x1F);
+    let fraction = f64::from(bits & 0x03FF);
+    sign * match exponent {
+        0 => fraction * 2f64.powi(-24),
+        0x1F if fraction == 0.0 => f64::INFINITY,
+        0x1F => f64::NAN,
+        _ => (1.0 + fraction / 1024.0) * 2f64.powi(exponent - 15),
+    }
+}
+
+/// Rounds to the nearest half float, ties to even.
+fn f16_from_f64(value: f64) -> u16 {
+    narrow_float(value, 5, 10)
+}
+
+fn bf16_to_f64(bits: u16) -> f64 {
+    f32::from_bits(u32::from(bits) << 16).into()
+}
+
+/// Rounds to the nearest bfloat16, ties to even.
+fn bf16_from_f64(value: f64) -> u16 {
+    narrow_float(value, 8, 7)
+}
+
+/// Rounds to the nearest float with the given field widths, ties to even. Rounding
+/// straight from the f64 bits, not through f32, avoids rounding twice.
+fn narrow_float(value: f64, exponent_bits: u32, mantissa_bits: u32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 63) << (exponent_bits + mantissa_bits)) as u16;
+    let max_exponent = (1i32 << exponent_bits) - 1;
+    let infinity = (max_exponent as u16) << mantissa_bits;
+    let exponent = ((bits >> 52) & 0x7FF) as i32;
+    let mantissa = bits & ((1 << 52) - 1);
+
+    if exponent == 0x7FF {
+        let quiet = if mantissa != 0 {
+            1 << (mantissa_bits - 1)
+        } else {
+            0
+        };
+        return sign | infinity | quiet;
+    }
+
+    let exponent = exponent - 1023 + (max_exponent >> 1);
+    if exponent >= max_exponent {
+        return sign | infinity;
+    }
+
+    // Subnormals keep the implicit bit and shift it in below the exponent
+    let (exponent, mantissa, shift) = if exponent > 0 {
+        (exponent, mantissa, 52 - mantissa_bits)
+    } else if exponent >= -(mantissa_bits as i32) {
+        (
+            0,
+            mantissa | (1 << 52),
+            (53 - mantissa_bits as i32 - exponent) as u32,
+        )
+    } else {
+        return sign;
+    };
+
+    let rounded = (mantissa + (1 << (shift - 1)) - 1 + ((mantissa >> shift) & 1)) >> shift;
+    let narrowed = ((exponent as u64) << mantissa_bits) + rounded;
+    sign | narrowed.min(u64::from(infinity)) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::FloatFormat;
+
+    const LE: Endianness = Endianness::Little;
+    const BE: Endianness = Endianness::Big;
+
+    fn round_trip(text: &str, format: PreviewDataFormat, endianness: Endianness) -> Vec<u8> {
+        let value = parse(text, format).unwrap();
+        let bytes = encode(value, format, endianness).unwrap();
+        assert_eq!(decode(&bytes, format, endianness), Some(value), "{}", text);
+        bytes
+    }
+
+    fn float_text(data: &[u8], format: PreviewDataFormat, float_format: FloatFormat) -> String {
+        format_float(data, format, BE, float_format, 3).unwrap()
+    }
+
+    #[test]
+    fn integers() {
+        assert_eq!(
+            round_trip("0x1234", PreviewDataFormat::U16, LE),
+            [0x34, 0x12]
+        );
+        assert_eq!(
+            round_trip("0x1234", PreviewDataFormat::U16, BE),
+            [0x12, 0x34]
+        );
+        assert_eq!(round_trip("-1", PreviewDataFormat::I32, LE), [0xFF; 4]);
+        round_trip(
+            "-170141183460469231731687303715884105728",
+            PreviewDataFormat::I128,
+            BE,
+        );
+        round_trip(
+            "0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+            PreviewDataFormat::U128,
+            LE,
+        );
+        assert!(parse("256", PreviewDataFormat::U8).is_none());
+        assert!(parse("-129", PreviewDataFormat::I8).is_none());
+        assert!(decode(&[0x01], PreviewDataFormat::U16, LE).is_none());
+    }
+
+    #[test]
+    fn leb128() {
+        assert_eq!(
+            round_trip("624485", PreviewDataFormat::Uleb128, LE),
+            [0xE5, 0x8E, 0x26]
+        );
+        assert_eq!(
+            round_trip("-123456", PreviewDataFormat::Sleb128, LE),
+            [0xC0, 0xBB, 0x78]
+        );
+        for text in ["18446744073709551615", "0", "127", "128"] {
+            round_trip(text, PreviewDataFormat::Uleb128, LE);
+        }
+        for text in [
+            "-9223372036854775808",
+            "9223372036854775807",
+            "-1",
+            "63",
+            "64",
+            "-64",
+            "-65",
+        ] {
+            round_trip(text, PreviewDataFormat::Sleb128, LE);
+        }
+        // Endianness doesn't apply, and values stop at their last byte
+        assert_eq!(
+            decode(&[0x7F, 0xFF], PreviewDataFormat::Uleb128, BE),
+            Some(Value::Unsigned(127))
+        );
+        assert!(decode(&[0x80], PreviewDataFormat::Uleb128, LE).is_none());
+    }
+
+    #[test]
+    fn bcd() {
+        assert_eq!(
+            round_trip("12345678", PreviewDataFormat::Bcd, BE),
+            [0x12, 0x34, 0x56, 0x78]
+        );
+        assert_eq!(
+            round_trip("12345678", PreviewDataFormat::Bcd, LE),
+            [0x78, 0x56, 0x34, 0x12]
+        );
+        assert!(decode(&[0x1A, 0, 0, 0], PreviewDataFormat::Bcd, BE).is_none());
+        assert!(parse("100000000", PreviewDataFormat::Bcd).is_none());
+    }
+
+    #[test]
+    fn bitfield() {
+        assert_eq!(
+            round_trip("1010 0000_1111 0001", PreviewDataFormat::Bitfield, BE),
+            [0xA0, 0xF1]
+        );
+        assert_eq!(
+            parse("0b11", PreviewDataFormat::Bitfield),
+            Some(Value::Unsigned(3))
+        );
+        assert!(parse("102", PreviewDataFormat::Bitfield).is_none());
+    }
+
+    #[test]
+    fn f16_round_trips_every_value() {
+        for bits in 0..=u16::MAX {
+            let value = f16_to_f64(bits);
+            if value.is_nan() {
+                assert!(f16_to_f64(f16_from_f64(value)).is_nan());
+            } else {
+                assert_eq!(f16_from_f64(value), bits, "{:#06x}", bits);
+            }
+        }
+    }
+
+    #[test]
+    fn f16_rounding() {
+        assert_eq!(round_trip("1.5", PreviewDataFormat::F16, LE), [0x00, 0x3E]);
+        assert_eq!(
+            round_trip("65504", PreviewDataFormat::F16, LE),
+            [0xFF, 0x7B]
+        );
+        assert_eq!(round_trip("0.1", PreviewDataFormat::F16, LE), [0x66, 0x2E]);
+        // Overflow goes to infinity, the smallest subnormal survives
+        assert_eq!(f16_from_f64(1e6), 0x7C00);
+        assert_eq!(f16_from_f64(5.96e-8), 0x0001);
+        assert_eq!(f16_to_f64(0x0001), 2f64.powi(-24));
+        // Halfway between 1.0 and the next value, ties go to even
+        assert_eq!(f16_from_f64(1.0 + 2f64.powi(-11)), 0x3C00);
+        assert_eq!(f16_from_f64(1.0 + 3.0 * 2f64.powi(-11)), 0x3C02);
+        // Just above the tie, where rounding to f32 first would land on the tie
+        assert_eq!(f16_from_f64(1.0 + 2f64.powi(-11) + 2f64.powi(-40)), 0x3C01);
+        assert_eq!(f16_from_f64(-(2f64.powi(-25) + 2f64.powi(-50))), 0x8001);
+    }
+
+    #[test]
+    fn bf16_rounding() {
+        assert_eq!(round_trip("-2", PreviewDataFormat::BF16, LE), [0x00, 0xC0]);
+        round_trip("3.140625", PreviewDataFormat::BF16, LE);
+        assert_eq!(bf16_from_f64(1.0 + 2f64.powi(-8)), 0x3F80);
+        assert_eq!(bf16_from_f64(1.0 + 3.0 * 2f64.powi(-8)), 0x3F82);
+        assert_eq!(bf16_from_f64(1.0 + 2f64.powi(-8) + 2f64.powi(-40)), 0x3F81);
+        assert_eq!(bf16_from_f64(f64::MAX), 0x7F80);
+        assert!(bf16_to_f64(bf16_from_f64(f64::NAN)).is_nan());
+    }
+
+    #[test]
+    fn float_formats() {
+        let tenth = 0.1f32.to_be_bytes();
+        assert_eq!(
+            float_text(&tenth, PreviewDataFormat::F32, FloatFormat::Shortest),
+            "0.1"
+        );
+        assert_eq!(
+            float_text(&tenth, PreviewDataFormat::F32, FloatFormat::Scientific),
+            "1.000e-1"
+        );
+        assert_eq!(
+            float_text(&tenth, PreviewDataFormat::F32, FloatFormat::Fixed),
+            "0.100"
+        );
+        assert_eq!(
+            float_text(&[0x2E, 0x66], PreviewDataFormat::F16, FloatFormat::Shortest),
+            "0.1"
+        );
+        assert_eq!(
+            float_text(
+                &[0x40, 0x49],
+                PreviewDataFormat::BF16,
+                FloatFormat::Shortest
+            ),
+            "3.14"
+        );
+        assert!(format_float(&[0; 4], PreviewDataFormat::U32, BE, FloatFormat::Fixed, 3).is_none());
+    }
+
+    #[test]
+    fn hex_floats() {
+        let hex = |data: &[u8], format| float_text(data, format, FloatFormat::HexFloat);
+
+        assert_eq!(
+            hex(&3.0f64.to_be_bytes(), PreviewDataFormat::F64),
+            "0x1.8p+1"
+        );
+        assert_eq!(hex(&1.0f64.to_be_bytes(), PreviewDataFormat::F64), "0x1p+0");
+        assert_eq!(
+            hex(&(-0.0f64).to_be_bytes(), PreviewDataFormat::F64),
+            "-0x0p+0"
+        );
+        assert_eq!(
+            hex(&f32::from_bits(1).to_be_bytes(), PreviewDataFormat::F32),
+            "0x0.000002p-126"
+        );
+        assert_eq!(hex(&[0xFF, 0x80, 0, 0], PreviewDataFormat::F32), "-Inf");
+        assert_eq!(
+            hex(&[0x7F, 0xC0, 0, 0x01], PreviewDataFormat::F32),
+            "qNaN 0x1"
+        );
+        assert_eq!(
+            hex(&[0x7F, 0x80, 0, 0x01], PreviewDataFormat::F32),
+            "sNaN 0x1"
+        );
+        assert_eq!(hex(&[0x3E, 0x00], PreviewDataFormat::F16), "0x1.8p+0");
+        assert_eq!(hex(&[0x40, 0x49], PreviewDataFormat::BF16), "0x1.92p+1");
+    }
+}